@@ -0,0 +1,54 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use gat_lending_iterator::{LendingIterator, ToLendingIterator, Windows};
+
+const LEN: usize = 10_000;
+
+fn consume<I: Iterator<Item = String>>(mut windows: Windows<I>) -> usize {
+    let mut total = 0;
+    while let Some(window) = windows.next() {
+        total += window[0].len() + window[window.len() - 1].len();
+    }
+    total
+}
+
+/// Compares buffer capacities for windows of values that are expensive to move around
+/// one by one.
+fn bench_capacity(c: &mut Criterion) {
+    let strings: Vec<String> = (0..LEN).map(|i| i.to_string()).collect();
+    let mut group = c.benchmark_group("windows_with_capacity");
+    for size in [4, 64, 1024] {
+        for (name, capacity) in [
+            ("size", size),
+            ("size * 2 - 1 (windows)", size * 2 - 1),
+            ("size * 8", size * 8),
+        ] {
+            group.bench_with_input(BenchmarkId::new(name, size), &capacity, |b, &capacity| {
+                b.iter(|| {
+                    consume(
+                        strings
+                            .iter()
+                            .cloned()
+                            .windows_with_capacity(size, capacity),
+                    )
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+fn bench_copy(c: &mut Criterion) {
+    c.bench_function("windows u64 64", |b| {
+        b.iter(|| {
+            let mut windows = (0..LEN as u64).windows(64);
+            let mut total = 0;
+            while let Some(window) = windows.next() {
+                total += window[0];
+            }
+            black_box(total)
+        });
+    });
+}
+
+criterion_group!(benches, bench_capacity, bench_copy);
+criterion_main!(benches);
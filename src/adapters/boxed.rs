@@ -0,0 +1,78 @@
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::{DynLendingIterator, ForLt, LendingIterator};
+
+/// A boxed lending iterator whose items are named by the [`ForLt`] `L`, which can hold
+/// lending iterators of different types.
+///
+/// This `struct` is created by the [`boxed`] method on [`LendingIterator`]. See its
+/// documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`boxed`]: crate::LendingIterator::boxed
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct BoxedLendingIterator<'a, L: ForLt + ?Sized> {
+    iter: Box<dyn DynLendingIterator<L> + 'a>,
+}
+
+impl<'a, L: ForLt + ?Sized> BoxedLendingIterator<'a, L> {
+    pub(crate) fn new<I>(iter: I) -> BoxedLendingIterator<'a, L>
+    where
+        I: DynLendingIterator<L> + 'a,
+    {
+        BoxedLendingIterator {
+            iter: Box::new(iter),
+        }
+    }
+}
+
+impl<L: ForLt + ?Sized> fmt::Debug for BoxedLendingIterator<'_, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedLendingIterator")
+            .finish_non_exhaustive()
+    }
+}
+
+impl<L: ForLt + ?Sized> LendingIterator for BoxedLendingIterator<'_, L> {
+    type Item<'a> = L::Of<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BoxedLendingIterator, LendingIterator, Owned, Ref, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut pipelines: Vec<BoxedLendingIterator<Ref<[u8]>>> = vec![
+            (0..4).windows(2).boxed::<Ref<_>>(),
+            (0..6).windows(3).step_by(2).boxed::<Ref<_>>(),
+            [1, 2].into_iter().windows(2).boxed::<Ref<_>>(),
+        ];
+        let mut firsts = Vec::new();
+        for pipeline in &mut pipelines {
+            assert!(pipeline.size_hint().0 > 0);
+            while let Some(w) = pipeline.next() {
+                firsts.push(w[0]);
+            }
+        }
+        assert_eq!(firsts, vec![0, 1, 2, 0, 2, 1]);
+
+        let counts = (0..3)
+            .into_lending()
+            .map(|x| x * 2)
+            .boxed::<Owned<i32>>()
+            .boxed::<Owned<i32>>();
+        assert_eq!(counts.into_owned_iter().collect::<Vec<_>>(), vec![0, 2, 4]);
+    }
+}
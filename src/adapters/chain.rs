@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use crate::{
+    DoubleEndedLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
 
 /// A lending iterator that iterates over the elements of two iterators
 /// in sequence.
@@ -25,6 +27,8 @@ impl<A, B> Chain<A, B> {
     }
 }
 
+// `B::Item<'a>` can only be named when `B: 'a`, so equating it with `A::Item<'a>` for
+// every `'a` requires `B: 'static`. See the limitations of `LendingIterator::chain`.
 impl<A, B> LendingIterator for Chain<A, B>
 where
     A: LendingIterator,
@@ -45,4 +49,81 @@ where
             })
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.a_done {
+            return self.b.size_hint();
+        }
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+
+        let lower = a_lower.saturating_add(b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => x.checked_add(y),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> DoubleEndedLendingIterator for Chain<A, B>
+where
+    A: DoubleEndedLendingIterator,
+    for<'a> B: DoubleEndedLendingIterator<Item<'a> = A::Item<'a>> + 'a,
+{
+    fn next_back(&mut self) -> Option<A::Item<'_>> {
+        self.b.next_back().or_else(|| {
+            if self.a_done {
+                None
+            } else {
+                self.a.next_back()
+            }
+        })
+    }
+}
+
+impl<A, B> FusedLendingIterator for Chain<A, B>
+where
+    A: FusedLendingIterator,
+    for<'a> B: FusedLendingIterator<Item<'a> = A::Item<'a>> + 'a,
+{
+}
+
+impl<A, B> HasNextLendingIterator for Chain<A, B>
+where
+    A: HasNextLendingIterator,
+    for<'a> B: HasNextLendingIterator<Item<'a> = A::Item<'a>> + 'a,
+{
+    fn has_next(&mut self) -> bool {
+        (!self.a_done && self.a.has_next()) || self.b.has_next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+    #[test]
+    fn test_size_hint() {
+        let mut iter = (0..2).chain(3..6).filter(|_| true);
+        let mut lending = (0..2)
+            .into_lending()
+            .chain((3..6).into_lending())
+            .filter(|_| true);
+        assert_eq!(iter.size_hint(), lending.size_hint());
+        assert_eq!(iter.nth(2), lending.nth(2));
+        assert_eq!(iter.size_hint(), lending.size_hint());
+
+        let max = || core::iter::repeat(()).take(usize::MAX);
+        assert_eq!(
+            max().into_lending().chain(max().into_lending()).size_hint(),
+            max().chain(max()).size_hint()
+        );
+        assert_eq!(
+            (0..5)
+                .into_lending()
+                .chain(core::iter::repeat(0).into_lending())
+                .size_hint(),
+            (0..5).chain(core::iter::repeat(0)).size_hint()
+        );
+    }
 }
@@ -1,6 +1,6 @@
 use ::core::ops::Deref;
 
-use crate::LendingIterator;
+use crate::{ExactSizeLendingIterator, FusedLendingIterator, LendingIterator};
 
 /// A lending iterator that clones the elements of an underlying lending iterator.
 ///
@@ -34,6 +34,29 @@ where
     fn next(&mut self) -> Option<Self::Item<'_>> {
         self.iter.next().map(|item| item.deref().clone())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> ExactSizeLendingIterator for Cloned<I>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: Clone,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> FusedLendingIterator for Cloned<I>
+where
+    I: FusedLendingIterator,
+    for<'a> I::Item<'a>: Deref,
+    for<'a> <I::Item<'a> as Deref>::Target: Clone,
+{
 }
 
 #[derive(Clone, Debug)]
@@ -53,6 +76,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(|item| item.deref().clone())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
 impl<I, T> IntoIterator for Cloned<I>
@@ -0,0 +1,221 @@
+use core::num::NonZeroUsize;
+
+use stable_try_trait_v2::Try;
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+};
+
+/// A lending iterator that is one of two lending iterators with the same items.
+///
+/// This allows picking between two pipelines at runtime, and returning either one from
+/// the same function without boxing it.
+///
+/// ```
+/// # use gat_lending_iterator::{EitherLending, LendingIterator, ToLendingIterator};
+/// let wide = true;
+/// let mut windows = if wide {
+///     EitherLending::Left((0..9).windows(5).step_by(2))
+/// } else {
+///     EitherLending::Right((0..9).windows(3))
+/// };
+/// assert_eq!(windows.next(), Some([0, 1, 2, 3, 4].as_slice()));
+/// assert_eq!(windows.next(), Some([2, 3, 4, 5, 6].as_slice()));
+/// ```
+///
+/// # Limitations
+///
+/// As with [`chain`](crate::LendingIterator::chain), both lending iterators must lend the
+/// same items for every lifetime, which the compiler can currently only prove when the
+/// second one is `'static`. So it can't borrow from a local:
+///
+/// ```compile_fail
+/// # use gat_lending_iterator::{EitherLending, LendingIterator, ToLendingIterator};
+/// let (a, b) = (vec![1, 2], vec![3]);
+/// let front = false;
+/// let mut refs = if front {
+///     EitherLending::Left(a.iter().lend_refs())
+/// } else {
+///     EitherLending::Right(b.iter().lend_refs())
+/// };
+/// assert_eq!(refs.next(), Some(&&3));
+/// ```
+///
+/// Moving the data into the lending iterators works:
+///
+/// ```
+/// # use gat_lending_iterator::{EitherLending, LendingIterator, ToLendingIterator};
+/// let (a, b) = (vec![1, 2], vec![3]);
+/// let front = false;
+/// let mut refs = if front {
+///     EitherLending::Left(a.into_iter().lend_refs())
+/// } else {
+///     EitherLending::Right(b.into_iter().lend_refs())
+/// };
+/// assert_eq!(refs.next(), Some(&3));
+/// assert_eq!(refs.next(), None);
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub enum EitherLending<L, R> {
+    /// The first lending iterator.
+    Left(L),
+    /// The second lending iterator.
+    Right(R),
+}
+
+macro_rules! either {
+    ($value:expr, $pattern:pat => $result:expr) => {
+        match $value {
+            EitherLending::Left($pattern) => $result,
+            EitherLending::Right($pattern) => $result,
+        }
+    };
+}
+
+// Equating `R::Item<'a>` with `L::Item<'a>` for every `'a` requires `R: 'static`, see
+// the limitations above.
+impl<L, R> LendingIterator for EitherLending<L, R>
+where
+    L: LendingIterator,
+    for<'a> R: LendingIterator<Item<'a> = L::Item<'a>> + 'a,
+{
+    type Item<'a> = L::Item<'a>
+        where
+            Self: 'a
+    ;
+
+    #[inline]
+    fn next(&mut self) -> Option<L::Item<'_>> {
+        either!(self, iter => iter.next())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        either!(self, iter => iter.size_hint())
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        either!(self, iter => iter.advance_by(n))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<L::Item<'_>> {
+        either!(self, iter => iter.nth(n))
+    }
+
+    #[inline]
+    fn try_fold<B, F, T>(&mut self, init: B, f: F) -> T
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, L::Item<'all>) -> T,
+        T: Try<Output = B>,
+    {
+        either!(self, iter => iter.try_fold(init, f))
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, L::Item<'all>) -> B,
+    {
+        either!(self, iter => iter.fold(init, f))
+    }
+}
+
+impl<L, R> DoubleEndedLendingIterator for EitherLending<L, R>
+where
+    L: DoubleEndedLendingIterator,
+    for<'a> R: DoubleEndedLendingIterator<Item<'a> = L::Item<'a>> + 'a,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<L::Item<'_>> {
+        either!(self, iter => iter.next_back())
+    }
+
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        either!(self, iter => iter.advance_back_by(n))
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<L::Item<'_>> {
+        either!(self, iter => iter.nth_back(n))
+    }
+
+    #[inline]
+    fn try_rfold<B, F, T>(&mut self, init: B, f: F) -> T
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, L::Item<'all>) -> T,
+        T: Try<Output = B>,
+    {
+        either!(self, iter => iter.try_rfold(init, f))
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, L::Item<'all>) -> B,
+    {
+        either!(self, iter => iter.rfold(init, f))
+    }
+}
+
+impl<L, R> ExactSizeLendingIterator for EitherLending<L, R>
+where
+    L: ExactSizeLendingIterator,
+    for<'a> R: ExactSizeLendingIterator<Item<'a> = L::Item<'a>> + 'a,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        either!(self, iter => iter.len())
+    }
+}
+
+impl<L, R> FusedLendingIterator for EitherLending<L, R>
+where
+    L: FusedLendingIterator,
+    for<'a> R: FusedLendingIterator<Item<'a> = L::Item<'a>> + 'a,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use core::ops::Range;
+
+    use crate::{
+        DoubleEndedLendingIterator, EitherLending, ExactSizeLendingIterator, IntoLending,
+        LendingIterator, ToLendingIterator,
+    };
+
+    fn pipeline(wide: bool) -> impl for<'a> ExactSizeLendingIterator<Item<'a> = &'a [usize]> {
+        if wide {
+            EitherLending::Left((0..9).windows(5).step_by(2))
+        } else {
+            EitherLending::Right((0..9).windows(3))
+        }
+    }
+
+    #[test]
+    fn test() {
+        let mut wide = pipeline(true);
+        assert_eq!(wide.len(), 3);
+        assert_eq!(wide.nth(1), Some([2, 3, 4, 5, 6].as_slice()));
+        assert_eq!(wide.fold(0, |acc, w| acc + w[0]), 4);
+
+        let mut narrow = pipeline(false);
+        assert_eq!(narrow.size_hint(), (7, Some(7)));
+        assert_eq!(narrow.advance_by(5), Ok(()));
+        assert_eq!(narrow.try_fold(0, |acc, w| Some(acc + w[2])), Some(15));
+
+        let mut reversed: EitherLending<IntoLending<Range<i32>>, _> =
+            EitherLending::Right((0..4).into_lending().rev());
+        assert_eq!(reversed.next_back(), Some(0));
+        assert_eq!(reversed.nth_back(1), Some(2));
+        assert_eq!(reversed.rfold(0, |acc, x| acc + x), 3);
+    }
+}
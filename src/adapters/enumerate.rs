@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+};
 
 /// A lending iterator that yields the current count and the element during iteration.
 #[derive(Clone, Debug)]
@@ -22,8 +24,37 @@ impl<I: LendingIterator> LendingIterator for Enumerate<I> {
         self.count += 1;
         Some((count, item))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedLendingIterator for Enumerate<I>
+where
+    I: DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        let len = self.iter.len();
+        let item = self.iter.next_back()?;
+        Some((self.count + len - 1, item))
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        let len = self.iter.len();
+        let item = self.iter.nth_back(n)?;
+        Some((self.count + len - n - 1, item))
+    }
 }
 
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Enumerate<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedLendingIterator> FusedLendingIterator for Enumerate<I> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -81,4 +112,16 @@ mod test {
         assert_eq!((second, second), (delay_iter.next(), delay_lending.next()));
         assert_eq!((None, None), (delay_iter.next(), delay_lending.next()));
     }
+
+    #[test]
+    fn test_back() {
+        let chars = vec!['a', 'b', 'c', 'd'];
+        let mut iter = chars.clone().into_iter().enumerate();
+        let mut lending = chars.into_lending().enumerate();
+        assert_eq!(iter.next(), lending.next());
+        assert_eq!(iter.next_back(), lending.next_back());
+        assert_eq!(iter.nth_back(0), lending.nth_back(0));
+        assert_eq!(iter.next(), lending.next());
+        assert_eq!(iter.next_back(), lending.next_back());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{DoubleEndedLendingIterator, FusedLendingIterator, LendingIterator};
 
 /// A lending iterator that filters the elements of `iter` with `predicate`.
 ///
@@ -42,4 +42,36 @@ where
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
+
+impl<I, P> DoubleEndedLendingIterator for Filter<I, P>
+where
+    I: DoubleEndedLendingIterator,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        loop {
+            // SAFETY: see https://docs.rs/polonius-the-crab/0.3.1/polonius_the_crab/#the-arcanemagic
+            let self_ = unsafe { &mut *(self as *mut Self) };
+            if let Some(item) = self_.iter.next_back() {
+                if (self_.predicate)(&item) {
+                    return Some(item);
+                }
+            } else {
+                return None;
+            }
+        }
+    }
+}
+
+impl<I, P> FusedLendingIterator for Filter<I, P>
+where
+    I: FusedLendingIterator,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
 }
@@ -1,4 +1,4 @@
-use crate::{LendingIterator, OptionTrait, SingleArgFnMut, SingleArgFnOnce};
+use crate::{FusedLendingIterator, LendingIterator, OptionTrait, SingleArgFnMut, SingleArgFnOnce};
 
 /// A lending iterator that uses `f` to both filter and map elements from `iter`.
 ///
@@ -44,4 +44,17 @@ where
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
+
+impl<I, F> FusedLendingIterator for FilterMap<I, F>
+where
+    I: FusedLendingIterator,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+    for<'a> <F as SingleArgFnOnce<I::Item<'a>>>::Output: OptionTrait,
+{
 }
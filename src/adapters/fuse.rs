@@ -0,0 +1,142 @@
+use core::num::NonZeroUsize;
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+};
+
+/// A lending iterator that yields `None` forever after the underlying iterator
+/// yields `None` once.
+///
+/// This `struct` is created by the [`fuse`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`fuse`]: crate::LendingIterator::fuse
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Fuse<I> {
+    iter: I,
+    done: bool,
+}
+
+impl<I> Fuse<I> {
+    pub(crate) fn new(iter: I) -> Fuse<I> {
+        Fuse { iter, done: false }
+    }
+}
+
+impl<I> LendingIterator for Fuse<I>
+where
+    I: LendingIterator,
+{
+    type Item<'a> = I::Item<'a> where I: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next();
+        self.done = item.is_none();
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        if self.done {
+            return NonZeroUsize::new(n).map_or(Ok(()), Err);
+        }
+        let result = self.iter.advance_by(n);
+        self.done = result.is_err();
+        result
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.nth(n);
+        self.done = item.is_none();
+        item
+    }
+}
+
+impl<I> DoubleEndedLendingIterator for Fuse<I>
+where
+    I: DoubleEndedLendingIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        let item = self.iter.next_back();
+        self.done = item.is_none();
+        item
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Fuse<I> {}
+
+impl<I: LendingIterator> FusedLendingIterator for Fuse<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{FusedLendingIterator, LendingIterator, ToLendingIterator};
+
+    // Yields `None` every other call, forever
+    struct Flaky(bool);
+
+    impl LendingIterator for Flaky {
+        type Item<'a> = &'a bool where Self: 'a;
+        fn next(&mut self) -> Option<Self::Item<'_>> {
+            self.0 = !self.0;
+            if self.0 {
+                Some(&self.0)
+            } else {
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn test() {
+        let mut flaky = Flaky(false);
+        assert_eq!(flaky.next(), Some(&true));
+        assert_eq!(flaky.next(), None);
+        assert_eq!(flaky.next(), Some(&true));
+
+        let mut fused = Flaky(false).fuse();
+        assert_eq!(fused.next(), Some(&true));
+        assert_eq!(fused.next(), None);
+        assert_eq!(fused.next(), None);
+        assert_eq!(fused.size_hint(), (0, Some(0)));
+
+        let mut fused = (0..3).into_lending().fuse();
+        assert_eq!(fused.nth(5), None);
+        assert_eq!(fused.advance_by(0), Ok(()));
+        assert!(fused.advance_by(1).is_err());
+    }
+
+    fn assert_fused<I: FusedLendingIterator>(_: &I) {}
+
+    #[test]
+    fn test_marker() {
+        fn double(x: usize) -> usize {
+            x * 2
+        }
+        assert_fused(&(0..5).windows(2).zip(vec![0, 1].lend_refs()).enumerate());
+        assert_fused(&(0..5).into_lending().rev().map(double).skip(1).take(2));
+        assert_fused(&Flaky(false).fuse().step_by(2));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+};
+
+/// A lending iterator that calls a function with a reference to each element before
+/// lending it.
+///
+/// This `struct` is created by the [`inspect`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`inspect`]: crate::LendingIterator::inspect
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Inspect<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> Inspect<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I, F> LendingIterator for Inspect<I, F>
+where
+    I: LendingIterator,
+    F: for<'a> FnMut(&I::Item<'a>),
+{
+    type Item<'a> = I::Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let item = self.iter.next()?;
+        (self.f)(&item);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedLendingIterator for Inspect<I, F>
+where
+    I: DoubleEndedLendingIterator,
+    F: for<'a> FnMut(&I::Item<'a>),
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        let item = self.iter.next_back()?;
+        (self.f)(&item);
+        Some(item)
+    }
+}
+
+impl<I, F> ExactSizeLendingIterator for Inspect<I, F>
+where
+    I: ExactSizeLendingIterator,
+    F: for<'a> FnMut(&I::Item<'a>),
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, F> FusedLendingIterator for Inspect<I, F>
+where
+    I: FusedLendingIterator,
+    F: for<'a> FnMut(&I::Item<'a>),
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DoubleEndedLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut seen = Vec::new();
+        let mut lent = Vec::new();
+        let mut windows = (0..4)
+            .windows(2)
+            .inspect(|w: &&[i32]| seen.push(w.to_vec()));
+        while let Some(w) = windows.next() {
+            lent.push(w.to_vec());
+        }
+        drop(windows);
+        assert_eq!(seen, vec![vec![0, 1], vec![1, 2], vec![2, 3]]);
+        assert_eq!(seen, lent);
+
+        let mut seen = Vec::new();
+        let mut iter = (0..4).into_lending().inspect(|x| seen.push(*x));
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(iter.next_back(), Some(2));
+        drop(iter);
+        assert_eq!(seen, vec![3, 0, 2]);
+    }
+}
@@ -1,8 +1,13 @@
-use crate::{HasNextLendingIterator, LendingIterator};
+use crate::{FusedLendingIterator, HasNextLendingIterator, LendingIterator};
 
-// DISCUSS: I cannot think of a good use case for this.
-
-/// see [`Iterator::intersperse_with()`].
+/// A lending iterator that places an element generated by `separator` between
+/// adjacent elements of the underlying iterator.
+///
+/// This `struct` is created by the [`intersperse_with`] method on [`LendingIterator`].
+/// See its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`intersperse_with`]: crate::LendingIterator::intersperse_with
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct IntersperseWith<I, G> {
     separator: G,
@@ -33,19 +38,170 @@ where
     ;
 
     fn next(&mut self) -> Option<I::Item<'_>> {
-        if self.iter.has_next() {
-            if self.needs_sep {
-                self.needs_sep = false;
-                Some((self.separator)(&()))
-            } else {
-                let needs_sep = &mut self.needs_sep;
-                self.iter.next().map(|item| {
-                    *needs_sep = true;
-                    item
-                })
-            }
+        let separator = &mut self.separator;
+        intersperse_next(&mut self.iter, &mut self.needs_sep, || separator(&()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.needs_sep)
+    }
+}
+
+impl<I, G> FusedLendingIterator for IntersperseWith<I, G>
+where
+    I: HasNextLendingIterator + FusedLendingIterator,
+    for<'all> G: FnMut(&'all ()) -> I::Item<'all>,
+{
+}
+
+/// A lending iterator that places a clone of `separator` between adjacent elements of
+/// the underlying iterator.
+///
+/// This `struct` is created by the [`intersperse`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`intersperse`]: crate::LendingIterator::intersperse
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I, S> {
+    separator: S,
+    iter: I,
+    needs_sep: bool,
+}
+impl<I, S> Intersperse<I, S>
+where
+    I: HasNextLendingIterator,
+    for<'all> S: Clone + Into<I::Item<'all>>,
+{
+    pub(crate) fn new(iter: I, separator: S) -> Self {
+        Self {
+            iter,
+            separator,
+            needs_sep: false,
+        }
+    }
+}
+impl<I, S> LendingIterator for Intersperse<I, S>
+where
+    I: HasNextLendingIterator,
+    for<'all> S: Clone + Into<I::Item<'all>>,
+{
+    type Item<'a> = I::Item<'a>
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<I::Item<'_>> {
+        let separator = &self.separator;
+        intersperse_next(&mut self.iter, &mut self.needs_sep, || {
+            separator.clone().into()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.needs_sep)
+    }
+}
+
+impl<I, S> FusedLendingIterator for Intersperse<I, S>
+where
+    I: HasNextLendingIterator + FusedLendingIterator,
+    for<'all> S: Clone + Into<I::Item<'all>>,
+{
+}
+
+fn intersperse_next<'a, I, F>(
+    iter: &'a mut I,
+    needs_sep: &mut bool,
+    separator: F,
+) -> Option<I::Item<'a>>
+where
+    I: HasNextLendingIterator,
+    F: FnOnce() -> I::Item<'a>,
+{
+    if iter.has_next() {
+        if *needs_sep {
+            *needs_sep = false;
+            Some(separator())
         } else {
-            None
+            iter.next().map(|item| {
+                *needs_sep = true;
+                item
+            })
         }
+    } else {
+        None
+    }
+}
+
+fn intersperse_size_hint<I>(iter: &I, needs_sep: bool) -> (usize, Option<usize>)
+where
+    I: LendingIterator,
+{
+    let (lower, upper) = iter.size_hint();
+    // an item always comes before the first separator
+    let next_is_item = usize::from(!needs_sep);
+    (
+        lower.saturating_sub(next_is_item).saturating_add(lower),
+        upper.and_then(|upper| upper.saturating_sub(next_is_item).checked_add(upper)),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut out = Vec::new();
+        (0..4)
+            .windows(2)
+            .intersperse_with(|(): &()| -> &[i32] { &[-1] })
+            .for_each(|w| out.push(w.to_vec()));
+        assert_eq!(
+            out,
+            vec![vec![0, 1], vec![-1], vec![1, 2], vec![-1], vec![2, 3]]
+        );
+
+        let words = ["a", "b", "c"].into_iter().peekable().into_lending();
+        let sentence = words.intersperse(", ").collect_owned::<String>();
+        assert_eq!(sentence, "a, b, c");
+
+        let iter = (0..3).peekable().into_lending().intersperse(9);
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        assert_eq!(iter.collect_owned::<Vec<_>>(), vec![0, 9, 1, 9, 2]);
+
+        let empty = (0..0)
+            .windows(2)
+            .intersperse_with(|(): &()| -> &[i32] { &[-1] });
+        assert_eq!(empty.count(), 0);
+
+        let chained = (0..2)
+            .peekable()
+            .into_lending()
+            .chain((5..6).peekable().into_lending())
+            .take(2)
+            .intersperse(9);
+        assert_eq!(chained.collect_owned::<Vec<_>>(), vec![0, 9, 1]);
+
+        let mut lens = Vec::new();
+        vec![1, 2, 3]
+            .into_iter()
+            .lend_refs()
+            .zip((0..4).windows_mut(2))
+            .intersperse_with(|(): &()| -> (&i32, &mut [i32]) { (&0, &mut []) })
+            .for_each(|(x, w)| lens.push((*x, w.len())));
+        assert_eq!(lens, vec![(1, 2), (0, 0), (2, 2), (0, 0), (3, 2)]);
+
+        let mut out = Vec::new();
+        "abcd"
+            .chars()
+            .windows(2)
+            .filter(|w| w[0] != 'b')
+            .peekable()
+            .intersperse_with(|(): &()| -> &[char] { &['-'] })
+            .for_each(|w| out.extend_from_slice(w));
+        assert_eq!(out, ['a', 'b', '-', 'c', 'd']);
     }
 }
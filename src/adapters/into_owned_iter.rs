@@ -0,0 +1,118 @@
+use core::iter::FusedIterator;
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+    OptionTrait,
+};
+
+/// An iterator over the elements of a lending iterator whose items don't borrow from it.
+///
+/// This `struct` is created by the [`into_owned_iter`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`into_owned_iter`]: crate::LendingIterator::into_owned_iter
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoOwnedIter<I> {
+    iter: I,
+}
+
+impl<I> IntoOwnedIter<I> {
+    pub(crate) fn new(iter: I) -> IntoOwnedIter<I> {
+        IntoOwnedIter { iter }
+    }
+}
+
+// `I: for<'a> LendingIterator<Item<'a> = T>` can't be proven for `Item<'a>` where
+// `I: 'a` doesn't hold, so the item type is pinned through `OptionTrait` instead.
+impl<I, T> Iterator for IntoOwnedIter<I>
+where
+    I: LendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().into_option()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).into_option()
+    }
+}
+
+impl<I, T> DoubleEndedIterator for IntoOwnedIter<I>
+where
+    I: DoubleEndedLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().into_option()
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth_back(n).into_option()
+    }
+}
+
+impl<I, T> ExactSizeIterator for IntoOwnedIter<I>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, T> FusedIterator for IntoOwnedIter<I>
+where
+    I: FusedLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    fn even_square(x: usize) -> Option<usize> {
+        (x % 2 == 0).then_some(x * x)
+    }
+
+    #[test]
+    fn test() {
+        let words = ["a", "b", "c"];
+        let enumerated: Vec<_> = words.into_lending().enumerate().into_owned_iter().collect();
+        assert_eq!(
+            enumerated,
+            words.into_iter().enumerate().collect::<Vec<_>>()
+        );
+
+        let zipped = (0..3)
+            .into_lending()
+            .zip(words.into_lending())
+            .into_owned_iter()
+            .rev()
+            .collect::<Vec<_>>();
+        assert_eq!(zipped, vec![(2, "c"), (1, "b"), (0, "a")]);
+
+        let squares = (0..6)
+            .into_lending()
+            .filter_map(even_square)
+            .into_owned_iter();
+        assert_eq!(squares.collect::<Vec<_>>(), vec![0, 4, 16]);
+        assert_eq!((0..6).into_lending().into_owned_iter().len(), 6);
+    }
+}
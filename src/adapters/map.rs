@@ -1,4 +1,7 @@
-use crate::{LendingIterator, SingleArgFnMut, SingleArgFnOnce};
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+    SingleArgFnMut, SingleArgFnOnce,
+};
 
 /// A lending iterator that maps the elements of `iter` with `f`.
 ///
@@ -32,6 +35,37 @@ where
     fn next(&mut self) -> Option<Self::Item<'_>> {
         self.iter.next().map(&mut self.f)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedLendingIterator for Map<I, F>
+where
+    I: DoubleEndedLendingIterator,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.next_back().map(&mut self.f)
+    }
+}
+
+impl<I, F> ExactSizeLendingIterator for Map<I, F>
+where
+    I: ExactSizeLendingIterator,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, F> FusedLendingIterator for Map<I, F>
+where
+    I: FusedLendingIterator,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+{
 }
 
 /// An iterator that maps the elements of `iter` with `f`.
@@ -54,6 +88,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.iter.next().map(&mut self.f)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
 }
 
 impl<I, F, O> IntoIterator for Map<I, F>
@@ -0,0 +1,114 @@
+use crate::{LendingIterator, OptionTrait, SingleArgFnMut, SingleArgFnOnce};
+
+/// A lending iterator that only accepts elements while `f` returns `Some(_)`.
+///
+/// This `struct` is created by the [`map_while`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`map_while`]: crate::LendingIterator::map_while
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapWhile<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> MapWhile<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I, F> LendingIterator for MapWhile<I, F>
+where
+    I: LendingIterator,
+    F: for<'a> SingleArgFnMut<I::Item<'a>>,
+    for<'a> <F as SingleArgFnOnce<I::Item<'a>>>::Output: OptionTrait,
+{
+    type Item<'a> = <<F as SingleArgFnOnce<I::Item<'a>>>::Output as OptionTrait>::Item
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let item = self.iter.next()?;
+        (self.f)(item).into_option()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
+
+/// An iterator that only accepts elements while `f` returns `Some(_)`.
+///
+/// This `struct` is created when [`IntoIterator::into_iter`] is called on [`MapWhile`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, O> Iterator for IntoIter<I, F>
+where
+    I: LendingIterator,
+    F: FnMut(I::Item<'_>) -> Option<O>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        (self.f)(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, F, O> IntoIterator for MapWhile<I, F>
+where
+    I: LendingIterator,
+    F: FnMut(I::Item<'_>) -> Option<O>,
+{
+    type Item = O;
+    type IntoIter = IntoIter<I, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.iter,
+            f: self.f,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    fn first_if_even(slice: &[usize]) -> Option<&usize> {
+        slice.first().filter(|x| *x % 2 == 0)
+    }
+
+    #[test]
+    fn test() {
+        let mut xs = Vec::new();
+        let mut evens = [0, 2, 4, 5, 6].windows(2).map_while(first_if_even);
+        while let Some(x) = evens.next() {
+            xs.push(*x);
+        }
+        assert_eq!(xs, vec![0, 2, 4]);
+
+        let std = [1, 2, -3, 4].iter().map_while(|x| u32::try_from(*x).ok());
+        let lending = [1, 2, -3, 4]
+            .into_lending()
+            .map_while(|x: i32| u32::try_from(x).ok());
+        assert_eq!(
+            lending.into_iter().collect::<Vec<_>>(),
+            std.collect::<Vec<_>>()
+        );
+    }
+}
@@ -1,25 +1,51 @@
+#[cfg(feature = "alloc")]
+mod boxed;
 mod chain;
 mod cloned;
+mod either;
 mod enumerate;
 mod filter;
 mod filter_map;
+mod fuse;
+mod inspect;
 mod intersperse;
+mod into_owned_iter;
 mod map;
+mod map_while;
+#[cfg(feature = "alloc")]
+mod multipeek;
 mod peekable;
+mod peekable_owned;
+mod put_back;
+mod rev;
+mod scan;
 mod skip;
 mod skip_while;
 mod step_by;
 mod take;
 mod take_while;
 mod zip;
+#[cfg(feature = "alloc")]
+pub use self::boxed::BoxedLendingIterator;
 pub use self::chain::Chain;
 pub use self::cloned::Cloned;
+pub use self::either::EitherLending;
 pub use self::enumerate::Enumerate;
 pub use self::filter::Filter;
 pub use self::filter_map::FilterMap;
-pub use self::intersperse::IntersperseWith;
+pub use self::fuse::Fuse;
+pub use self::inspect::Inspect;
+pub use self::intersperse::{Intersperse, IntersperseWith};
+pub use self::into_owned_iter::IntoOwnedIter;
 pub use self::map::Map;
+pub use self::map_while::MapWhile;
+#[cfg(feature = "alloc")]
+pub use self::multipeek::MultiPeek;
 pub use self::peekable::Peekable;
+pub use self::peekable_owned::PeekableOwned;
+pub use self::put_back::PutBack;
+pub use self::rev::Rev;
+pub use self::scan::Scan;
 pub use self::skip::Skip;
 pub use self::step_by::StepBy;
 pub use self::take::Take;
@@ -0,0 +1,130 @@
+use alloc::collections::VecDeque;
+
+use crate::{ExactSizeLendingIterator, FusedLendingIterator, LendingIterator, OptionTrait};
+
+/// A lending iterator whose items don't borrow from it, which can peek arbitrarily far
+/// ahead.
+///
+/// Every call to [`peek`](MultiPeek::peek) advances a peeking cursor by one element,
+/// buffering the elements it passes. Calling [`next`](LendingIterator::next) or
+/// [`reset_peek`](MultiPeek::reset_peek) moves the cursor back to the next element.
+///
+/// This `struct` is created by the [`multipeek`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`multipeek`]: crate::LendingIterator::multipeek
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MultiPeek<I, T> {
+    iter: I,
+    buf: VecDeque<T>,
+    index: usize,
+}
+
+impl<I, T> MultiPeek<I, T> {
+    pub(crate) fn new(iter: I) -> MultiPeek<I, T> {
+        MultiPeek {
+            iter,
+            buf: VecDeque::new(),
+            index: 0,
+        }
+    }
+
+    /// Moves the peeking cursor back to the next element.
+    #[inline]
+    pub fn reset_peek(&mut self) {
+        self.index = 0;
+    }
+}
+
+impl<I, T> MultiPeek<I, T>
+where
+    I: LendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    /// Returns a reference to the element under the peeking cursor, and advances the
+    /// cursor.
+    pub fn peek(&mut self) -> Option<&T> {
+        if self.index == self.buf.len() {
+            let next = self.iter.next().into_option()?;
+            self.buf.push_back(next);
+        }
+        self.index += 1;
+        self.buf.get(self.index - 1)
+    }
+
+    /// Returns a mutable reference to the element under the peeking cursor, and
+    /// advances the cursor.
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.peek()?;
+        self.buf.get_mut(self.index - 1)
+    }
+}
+
+impl<I, T> LendingIterator for MultiPeek<I, T>
+where
+    I: LendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    type Item<'a> = T where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.index = 0;
+        self.buf
+            .pop_front()
+            .or_else(|| self.iter.next().into_option())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        (
+            lo.saturating_add(self.buf.len()),
+            hi.and_then(|hi| hi.checked_add(self.buf.len())),
+        )
+    }
+}
+
+impl<I, T> ExactSizeLendingIterator for MultiPeek<I, T>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+}
+
+impl<I, T> FusedLendingIterator for MultiPeek<I, T>
+where
+    I: FusedLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut iter = (0..4).into_lending().multipeek();
+        assert_eq!(iter.peek(), Some(&0));
+        assert_eq!(iter.peek(), Some(&1));
+        if let Some(x) = iter.peek_mut() {
+            *x *= 10;
+        }
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.peek(), Some(&1));
+        iter.reset_peek();
+        assert_eq!(iter.peek(), Some(&1));
+        assert_eq!(iter.peek(), Some(&20));
+        assert_eq!(iter.peek(), Some(&3));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(20));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -1,276 +1,497 @@
-use core::{fmt, marker::PhantomPinned, pin::Pin};
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{fmt, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
 
-use crate::LendingIterator;
-
-// REVIEW: I assumed the worst possible case is self-referencing, so I used Pin, but I'm unsure if that's really the case
+use crate::{
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
 
 /// A lending iterator with a `peek()` that returns an optional reference to the next
-/// element. requires pinning to peek.
+/// element.
+///
+/// The peeked element may borrow from the underlying lending iterator, so the iterator
+/// is either kept on the heap or borrowed, where the element stays valid when the
+/// `Peekable` is moved.
 ///
-/// This `struct` is created by the [`peekable`] method on [`LendingIterator`]. See its
-/// documentation for more.
+/// This `struct` is created by the [`peekable`] and [`peekable_by_ref`] methods on
+/// [`LendingIterator`]. See their documentation for more.
 ///
 /// [`peekable`]: LendingIterator::peekable
+/// [`peekable_by_ref`]: LendingIterator::peekable_by_ref
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 pub struct Peekable<'this, I: 'this>
 where
     I: LendingIterator,
 {
-    iter: I,
+    // A pointer rather than a `Box` or a `&mut`, since moving those would assert
+    // exclusive access to the iterator while the peeked element still borrows from it.
+    iter: NonNull<I>,
+    // Whether `iter` was leaked from a `Box`, rather than borrowed.
+    #[cfg(feature = "alloc")]
+    owned: bool,
     #[allow(clippy::option_option)]
-    peeked: Option<Option<I::Item<'this>>>,
-    _pin: PhantomPinned,
+    peeked: Option<Buffered<Option<I::Item<'this>>>>,
+    // The size hint of `iter` from before the peeked element was taken out of it.
+    hint: (usize, Option<usize>),
+    _marker: PhantomData<I>,
+}
+
+// SAFETY: `Peekable` behaves like a `Box<I>` or a `&mut I` alongside an `I::Item<'this>`.
+unsafe impl<'this, I> Send for Peekable<'this, I>
+where
+    I: LendingIterator + Send,
+    I::Item<'this>: Send,
+{
+}
+
+// SAFETY: `Peekable` behaves like a `Box<I>` or a `&mut I` alongside an `I::Item<'this>`.
+unsafe impl<'this, I> Sync for Peekable<'this, I>
+where
+    I: LendingIterator + Sync,
+    I::Item<'this>: Sync,
+{
+}
+
+/// The peeked element.
+///
+/// Moving a `Peekable` into a function would otherwise assert that the references in
+/// the peeked element stay valid for the whole call, even though the function may drop
+/// the element and advance the iterator it borrows from. The compiler makes no such
+/// assumptions about the contents of a `MaybeUninit`.
+struct Buffered<T>(MaybeUninit<T>);
+
+impl<T> Buffered<T> {
+    #[inline]
+    fn new(value: T) -> Buffered<T> {
+        Buffered(MaybeUninit::new(value))
+    }
+
+    #[inline]
+    fn into_inner(self) -> T {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: the value is initialized, and won't be dropped by `this`.
+        unsafe { this.0.assume_init_read() }
+    }
+}
+
+impl<T> core::ops::Deref for Buffered<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: the value is initialized until `Buffered` is dropped or consumed.
+        unsafe { self.0.assume_init_ref() }
+    }
+}
+
+impl<T> core::ops::DerefMut for Buffered<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: the value is initialized until `Buffered` is dropped or consumed.
+        unsafe { self.0.assume_init_mut() }
+    }
+}
+
+impl<T> Drop for Buffered<T> {
+    fn drop(&mut self) {
+        // SAFETY: the value is initialized, and is never used again.
+        unsafe { self.0.assume_init_drop() }
+    }
 }
 
 impl<'this, I> Peekable<'this, I>
 where
     I: LendingIterator,
 {
+    #[cfg(feature = "alloc")]
     pub(crate) fn new(iter: I) -> Peekable<'this, I> {
         Peekable {
-            iter,
+            iter: NonNull::from(Box::leak(Box::new(iter))),
+            owned: true,
             peeked: None,
-            _pin: PhantomPinned,
+            hint: (0, Some(0)),
+            _marker: PhantomData,
         }
     }
-    #[inline]
-    pub(crate) fn get_peeked(self: Pin<&mut Self>) -> &mut Option<I::Item<'this>> {
-        // SAFETY: we can return a mutable reference to peeked because any self-referencing to self.iter is pinned
-        unsafe {
-            // SAFETY: mutable references to self or fields do not move self
-            let this = self.get_unchecked_mut();
-            let iter = &mut this.iter;
-            this.peeked
-                .get_or_insert_with(#[inline] ||
-                    // SAFETY: We manually guarantee iter.next() is only called once per item, and we are pinning any possible self-referencing
-                    core::mem::transmute::<Option<I::Item<'_>>, Option<I::Item<'this>>>(iter.next())
-                )
+
+    pub(crate) fn new_borrowed(iter: &'this mut I) -> Peekable<'this, I> {
+        Peekable {
+            iter: NonNull::from(iter),
+            #[cfg(feature = "alloc")]
+            owned: false,
+            peeked: None,
+            hint: (0, Some(0)),
+            _marker: PhantomData,
         }
     }
-    /// Returns a reference to the next() value without advancing the iterator.
+
+    /// Returns the underlying iterator, which must not be used while an element is
+    /// peeked.
     #[inline]
-    pub fn peek(self: Pin<&mut Self>) -> Option<&I::Item<'this>> {
-        self.get_peeked().as_ref()
+    unsafe fn inner<'a>(&mut self) -> &'a mut I {
+        // SAFETY: `iter` is owned or exclusively borrowed by `self`, and the caller makes
+        // sure that no element borrowing from it is alive.
+        unsafe { self.iter.as_mut() }
     }
-    /// Returns a mutable reference to the next() value without advancing the iterator.
+
     #[inline]
-    pub fn peek_mut(self: Pin<&mut Self>) -> Option<&mut I::Item<'this>> {
-        self.get_peeked().as_mut()
+    fn get_peeked(&mut self) -> Option<&mut I::Item<'this>> {
+        if self.peeked.is_none() {
+            // SAFETY: nothing is peeked, and the elements lent before borrowed `self`
+            // mutably, so they are gone by now.
+            let iter = unsafe { self.inner() };
+            self.hint = iter.size_hint();
+            self.peeked = Some(Buffered::new(iter.next()));
+        }
+        self.peeked.as_deref_mut().and_then(Option::as_mut)
     }
-    /// Consume and return the next value of this iterator if a condition is true.
-    pub fn next_if<F>(self: Pin<&mut Self>, f: F) -> Option<I::Item<'_>>
+
+    /// Calls `f` with a reference to the `next()` value without advancing the iterator,
+    /// and returns its result.
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let mut iter = [1, 2].into_iter().lend_refs();
+    /// let mut peekable = iter.peekable_by_ref();
+    /// assert_eq!(peekable.peek(|x| **x), Some(1));
+    /// assert_eq!(peekable.next(), Some(&1));
+    /// ```
+    ///
+    /// Unlike [`Peekable::peek`](core::iter::Peekable::peek), the element is only lent to
+    /// a closure. Through a plain `&`, an element with interior mutability could be made
+    /// to hold a borrow that doesn't live as long as the `Peekable`, and `next` would then
+    /// lend it after it is gone:
+    ///
+    /// ```compile_fail
+    /// use core::cell::Cell;
+    /// use gat_lending_iterator::LendingIterator;
+    ///
+    /// struct Cells(u8);
+    ///
+    /// impl LendingIterator for Cells {
+    ///     type Item<'a> = Cell<&'a u8>;
+    ///
+    ///     fn next(&mut self) -> Option<Self::Item<'_>> {
+    ///         Some(Cell::new(&self.0))
+    ///     }
+    /// }
+    ///
+    /// let mut cells = Cells(0);
+    /// let mut peekable = cells.peekable_by_ref();
+    /// {
+    ///     let local = 1;
+    ///     peekable.peek(|x| x.set(&local));
+    /// }
+    /// assert_eq!(peekable.next().map(|x| *x.get()), Some(1));
+    /// ```
+    #[inline]
+    pub fn peek<F, R>(&mut self, f: F) -> Option<R>
     where
-        F: FnOnce(&I::Item<'this>) -> bool,
+        for<'all> F: FnOnce(&I::Item<'all>) -> R,
     {
-        // SAFETY: we use pin for self-referencing by peeked, so returning peeked is safe as long as we don't move self
-        unsafe {
-            let this = self.get_unchecked_mut();
-            let iter = &mut this.iter;
-            match &this.peeked {
-                Some(Some(v)) if f(v) => (),
-                None => return iter.next(),
-                _ => return None,
-            }
-            // SAFETY: 'this: 'a
-            core::mem::transmute::<Option<I::Item<'this>>, Option<I::Item<'_>>>(
-                this.peeked.take().unwrap_unchecked(),
-            )
-        }
+        self.get_peeked().map(|peeked| f(peeked))
     }
-    /// Consume and return the next item if it is equal to `expected`.
-    pub fn next_if_eq<'a, T>(self: Pin<&'a mut Self>, t: &T) -> Option<I::Item<'a>>
+
+    /// Calls `f` with a mutable reference to the `next()` value without advancing the
+    /// iterator, and returns its result.
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let mut iter = [1, 2].into_iter().lend_refs_mut();
+    /// let mut peekable = iter.peekable_by_ref();
+    /// peekable.peek_mut(|x| **x += 10);
+    /// assert_eq!(peekable.next(), Some(&mut 11));
+    /// ```
+    ///
+    /// Unlike [`Peekable::peek_mut`](core::iter::Peekable::peek_mut), the element is only
+    /// lent to a closure. With a plain `&mut`, the element could be replaced with one
+    /// that doesn't live as long as the `Peekable`, and `next` would then lend it after it
+    /// is gone:
+    ///
+    /// ```compile_fail
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let mut iter = [1, 2].into_iter().lend_refs_mut();
+    /// let mut peekable = iter.peekable_by_ref();
+    /// {
+    ///     let mut local = 3;
+    ///     peekable.peek_mut(|x| *x = &mut local);
+    /// }
+    /// assert_eq!(peekable.next(), Some(&mut 3));
+    /// ```
+    ///
+    /// When the items don't borrow from the lending iterator,
+    /// [`peekable_owned`](LendingIterator::peekable_owned) has a `peek_mut` that returns a
+    /// mutable reference.
+    #[inline]
+    pub fn peek_mut<F, R>(&mut self, f: F) -> Option<R>
     where
-        T: PartialEq<I::Item<'this>>,
+        for<'all> F: FnOnce(&mut I::Item<'all>) -> R,
     {
-        self.next_if(|v| t == v)
+        self.get_peeked().map(f)
     }
-    /// Drop any peeked value and unpin the iterator.
-    #[inline]
-    pub fn unpin(self: Pin<&mut Self>) -> &mut Self {
-        // SAFETY: we remove any possible self-referencing
-        unsafe {
-            let this = self.get_unchecked_mut();
-            this.peeked = None;
-            this
+
+    /// Consume and return the next value of this iterator if a condition is true.
+    ///
+    /// As with [`peek`](Self::peek), the condition is checked by a closure that accepts
+    /// the element for any lifetime.
+    ///
+    /// See [`Peekable::next_if`](core::iter::Peekable::next_if).
+    pub fn next_if<F>(&mut self, f: F) -> Option<I::Item<'_>>
+    where
+        for<'all> F: FnOnce(&I::Item<'all>) -> bool,
+    {
+        if self.peek(f)? {
+            self.next()
+        } else {
+            None
         }
     }
-}
 
-impl<'this, I: fmt::Debug> fmt::Debug for Peekable<'this, I>
-where
-    I: LendingIterator + fmt::Debug,
-    I::Item<'this>: fmt::Debug,
-{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Peekable")
-            .field("iter", &self.iter)
-            .field("peeked", &self.peeked)
-            .finish()
+    /// Consume and return the next item if it is equal to `expected`.
+    ///
+    /// See [`Peekable::next_if_eq`](core::iter::Peekable::next_if_eq).
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item<'_>>
+    where
+        T: ?Sized,
+        for<'all> I::Item<'all>: PartialEq<T>,
+    {
+        self.next_if(|next| next == expected)
     }
 }
 
-// using deref instead of implementing LendingIterator directly
-// allows us to propogate the specialized implementations of the underlying iterator
-// (self.peeked is always None when self is not Pinned)
-impl<'this, I> ::core::ops::Deref for Peekable<'this, I>
-where
-    I: LendingIterator,
-{
-    type Target = I;
-    #[inline]
-    fn deref(&self) -> &Self::Target {
-        &self.iter
-    }
-}
-impl<'this, I> ::core::ops::DerefMut for Peekable<'this, I>
-where
-    I: LendingIterator,
-{
-    #[inline]
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.iter
-    }
-}
-impl<'this, I> ::core::convert::AsRef<I> for Peekable<'this, I>
+impl<I> Drop for Peekable<'_, I>
 where
     I: LendingIterator,
 {
-    #[inline]
-    fn as_ref(&self) -> &I {
-        &self.iter
+    fn drop(&mut self) {
+        self.peeked = None;
+        #[cfg(feature = "alloc")]
+        if self.owned {
+            // SAFETY: `iter` was leaked in `new`, and the peeked element has been dropped.
+            drop(unsafe { Box::from_raw(self.iter.as_ptr()) });
+        }
     }
 }
-impl<'this, I> ::core::convert::AsMut<I> for Peekable<'this, I>
+
+impl<'this, I> fmt::Debug for Peekable<'this, I>
 where
     I: LendingIterator,
+    I::Item<'this>: fmt::Debug,
 {
-    #[inline]
-    fn as_mut(&mut self) -> &mut I {
-        &mut self.iter
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Peekable")
+            .field("peeked", &self.peeked.as_deref())
+            .finish_non_exhaustive()
     }
 }
 
-impl<'this, I> LendingIterator for Pin<&mut Peekable<'this, I>>
+impl<'this, I> LendingIterator for Peekable<'this, I>
 where
     I: LendingIterator,
 {
     type Item<'a> = I::Item<'a>
     where
         Self: 'a;
-    fn next<'a>(&'a mut self) -> Option<I::Item<'a>> {
-        // SAFETY: we remove any self-referencing, we are just incurring a double pointer indirection
-        unsafe {
-            let this = self.as_mut().get_unchecked_mut();
-            let iter = &mut this.iter;
-            match this.peeked.take() {
-                // SAFETY: 'this: 'a
-                Some(peeked) => {
-                    core::mem::transmute::<Option<I::Item<'this>>, Option<I::Item<'a>>>(peeked)
-                }
-                None => iter.next(),
-            }
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item<'_>> {
+        match self.peeked.take() {
+            // SAFETY: the element is lent for as long as `self` is borrowed.
+            Some(peeked) => unsafe {
+                core::mem::transmute::<Option<I::Item<'this>>, Option<I::Item<'_>>>(
+                    peeked.into_inner(),
+                )
+            },
+            // SAFETY: nothing is peeked.
+            None => unsafe { self.inner() }.next(),
         }
     }
+
     #[inline]
-    fn count(self) -> usize
+    fn count(mut self) -> usize
     where
         Self: Sized,
     {
-        // SAFETY: ...
-        unsafe {
-            let this = self.get_unchecked_mut();
-            let iter = &mut this.iter;
-            match this.peeked.take() {
-                Some(None) => 0,
-                Some(Some(_)) => 1 + iter.count(),
-                None => iter.count(),
-            }
-        }
+        let peeked = match self.peeked.take().map(Buffered::into_inner) {
+            Some(None) => return 0,
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        // SAFETY: the peeked element has been dropped.
+        peeked + unsafe { self.inner() }.count()
     }
+
     #[inline]
-    fn nth<'a>(&'a mut self, n: usize) -> Option<I::Item<'a>> {
-        // SAFETY: ...
-        unsafe {
-            let this = self.as_mut().get_unchecked_mut();
-            let iter = &mut this.iter;
-            match this.peeked.take() {
-                Some(None) => None,
-                // SAFETY: 'this: 'a
-                Some(Some(v)) if n == 0 => {
-                    Some(core::mem::transmute::<I::Item<'this>, I::Item<'a>>(v))
-                }
-                Some(Some(_)) => iter.nth(n - 1),
-                None => iter.nth(n),
+    fn nth(&mut self, n: usize) -> Option<I::Item<'_>> {
+        let n = match self.peeked.take().map(Buffered::into_inner) {
+            Some(None) => return None,
+            Some(Some(peeked)) if n == 0 => {
+                // SAFETY: the element is lent for as long as `self` is borrowed.
+                return Some(unsafe {
+                    core::mem::transmute::<I::Item<'this>, I::Item<'_>>(peeked)
+                });
             }
-        }
+            Some(Some(_)) => n - 1,
+            None => n,
+        };
+        // SAFETY: the peeked element has been dropped.
+        unsafe { self.inner() }.nth(n)
     }
+
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let this = self.as_ref().get_ref();
-        let iter = &this.iter;
-        match this.peeked {
+        match self.peeked.as_deref() {
             Some(None) => (0, Some(0)),
-            Some(Some(_)) => (1, None),
-            None => iter.size_hint(),
+            Some(Some(_)) => (self.hint.0.max(1), self.hint.1),
+            // SAFETY: nothing is peeked, and the iterator is only read.
+            None => unsafe { self.iter.as_ref() }.size_hint(),
         }
     }
-    // fn try_fold cannot be specialized as of 1.75.0
+
     #[inline]
-    fn fold<B, F>(self, init: B, mut f: F) -> B
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
     where
         Self: Sized,
         for<'all> F: FnMut(B, I::Item<'all>) -> B,
     {
-        // SAFETY: ...
-        unsafe {
-            let this = self.get_unchecked_mut();
-            let iter = &mut this.iter;
-            let mut acc = match this.peeked.take() {
-                Some(None) => return init,
-                Some(Some(v)) => f(init, v),
-                None => init,
-            };
-            // iter.fold moves iter so it violates &mut Self.
-            while let Some(x) = iter.next() {
-                acc = f(acc, x);
-            }
-            acc
+        let mut acc = match self.peeked.take().map(Buffered::into_inner) {
+            Some(None) => return init,
+            Some(Some(peeked)) => f(init, peeked),
+            None => init,
+        };
+        // SAFETY: the peeked element has been consumed.
+        let iter = unsafe { self.inner() };
+        // `iter.fold` would require `F` to accept the elements of `&mut I`.
+        while let Some(x) = iter.next() {
+            acc = f(acc, x);
         }
+        acc
+    }
+}
+
+impl<I> ExactSizeLendingIterator for Peekable<'_, I> where I: ExactSizeLendingIterator {}
+
+impl<I> FusedLendingIterator for Peekable<'_, I> where I: FusedLendingIterator {}
+
+impl<I> HasNextLendingIterator for Peekable<'_, I>
+where
+    I: LendingIterator,
+{
+    #[inline]
+    fn has_next(&mut self) -> bool {
+        self.get_peeked().is_some()
     }
 }
 
 #[cfg(test)]
 mod test {
-    use core::pin::pin;
+    use core::cell::Cell;
+
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
 
-    use super::*;
-    use crate::ToLendingIterator;
     #[test]
     fn test() {
         assert_eq!(
-            Peekable::new((0..5).into_lending()).as_mut().skip(1).nth(1),
+            (0..5).into_lending().peekable().skip(1).nth(1),
             (0..5).skip(1).nth(1)
         );
         assert_eq!(
-            pin!(Peekable::new((0..5).into_lending())).peek(),
-            (0..5).peekable().peek()
+            (0..5).into_lending().peekable().peek(|x| *x),
+            (0..5).peekable().peek().copied()
         );
 
-        let mut peekable = Peekable::new(vec![0, 1].into_lending());
-        assert_eq!(peekable.next(), Some(0));
+        let mut peekable = vec![0, 1, 2].into_lending().peekable();
+        assert_eq!(peekable.peek(|x| *x), Some(0));
+        assert_eq!(peekable.peek(|x| *x), Some(0));
+        assert_eq!(peekable.len(), 3);
+        assert_eq!(peekable.peek_mut(|x| *x += 10), Some(()));
+        assert_eq!(peekable.next(), Some(10));
         assert_eq!(peekable.next(), Some(1));
-        assert_eq!(peekable.next(), None);
-        peekable = Peekable::new(vec![0, 1, 2].into_lending());
-        {
-            let mut pin = pin!(peekable);
-            assert_eq!(pin.as_mut().peek(), Some(&0));
-            assert_eq!(pin.as_mut().peek(), Some(&0));
-            assert_eq!(pin.as_mut().peek_mut(), Some(&mut 0));
-            assert_eq!(pin.as_mut().next(), Some(0));
-            assert_eq!(pin.as_mut().next(), Some(1));
-            assert_eq!(pin.as_mut().peek(), Some(&2));
-            let peekable = pin.unpin();
-            assert_eq!(peekable.next(), None);
+        assert_eq!(peekable.peek(|x| *x), Some(2));
+        assert_eq!(peekable.count(), 1);
+    }
+
+    #[test]
+    fn test_next_if() {
+        let mut peekable = (0..6).windows(2).peekable();
+        assert_eq!(peekable.next_if(|w| w[0] == 1), None);
+        assert_eq!(peekable.next_if_eq(&vec![0, 1]), Some([0, 1].as_slice()));
+        assert_eq!(peekable.next_if(|w| w[0] == 1), Some([1, 2].as_slice()));
+        assert_eq!(peekable.peek(|w| w.to_vec()), Some(vec![2, 3]));
+        assert_eq!(peekable.size_hint(), (3, Some(3)));
+        assert_eq!(peekable.fold(0, |acc, w| acc + w[1]), 12);
+    }
+
+    #[test]
+    fn test_invariant() {
+        // An item that is invariant in its lifetime, so a peeked element must never be
+        // made to hold a borrow that `next` could lend after it is gone.
+        struct Cells(u8);
+
+        impl LendingIterator for Cells {
+            type Item<'a> = Cell<&'a u8>;
+
+            fn next(&mut self) -> Option<Self::Item<'_>> {
+                self.0 += 1;
+                (self.0 < 4).then(|| Cell::new(&self.0))
+            }
         }
+
+        static SEVEN: u8 = 7;
+
+        let mut peekable = Cells(0).peekable();
+        assert_eq!(peekable.peek(|x| *x.get()), Some(1));
+        peekable.peek(|x| x.set(&SEVEN));
+        assert_eq!(peekable.peek(|x| *x.get()), Some(7));
+        let next_if = peekable.next_if(|x| {
+            x.set(&SEVEN);
+            false
+        });
+        assert!(next_if.is_none());
+        assert_eq!(peekable.next().map(|x| *x.get()), Some(7));
+        assert_eq!(
+            peekable.next_if(|x| *x.get() == 2).map(|x| *x.get()),
+            Some(2)
+        );
+        assert_eq!(peekable.peek_mut(|x| **x.get_mut()), Some(3));
+        assert_eq!(peekable.nth(0).map(|x| *x.get()), Some(3));
+        assert!(peekable.next().is_none());
+    }
+
+    #[test]
+    fn test_move() {
+        // An iterator lending its own fields, which a moved `Peekable` must not
+        // invalidate.
+        struct Counter([usize; 2]);
+
+        impl LendingIterator for Counter {
+            type Item<'a> = &'a mut [usize; 2];
+
+            fn next(&mut self) -> Option<Self::Item<'_>> {
+                self.0[0] += 1;
+                (self.0[0] < 4).then_some(&mut self.0)
+            }
+        }
+
+        let mut peekable = Counter([0, 0]).peekable();
+        peekable.peek_mut(|x| x[1] = 7);
+        let mut moved = Some(peekable);
+        let mut peekable = moved.take().unwrap();
+        assert_eq!(peekable.peek(|x| **x), Some([1, 7]));
+        assert_eq!(peekable.next(), Some(&mut [1, 7]));
+        assert_eq!(peekable.nth(1), Some(&mut [3, 7]));
+        assert_eq!(peekable.next(), None);
+
+        let mut counter = Counter([0, 0]);
+        let mut peekable = counter.peekable_by_ref();
+        peekable.peek_mut(|x| x[1] = 7);
+        let mut peekable = core::convert::identity(peekable);
+        assert_eq!(peekable.next(), Some(&mut [1, 7]));
+        assert_eq!(peekable.peek(|x| **x), Some([2, 7]));
+        assert_eq!(peekable.count(), 2);
+        assert_eq!(counter.next(), None);
     }
 }
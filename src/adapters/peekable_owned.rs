@@ -0,0 +1,205 @@
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+    OptionTrait,
+};
+
+/// A lending iterator whose items don't borrow from it, with a `peek()` that returns an
+/// optional reference to the next element.
+///
+/// This `struct` is created by the [`peekable_owned`] method on [`LendingIterator`].
+/// See its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`peekable_owned`]: crate::LendingIterator::peekable_owned
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PeekableOwned<I, T> {
+    iter: I,
+    #[allow(clippy::option_option)]
+    peeked: Option<Option<T>>,
+}
+
+impl<I, T> PeekableOwned<I, T> {
+    pub(crate) fn new(iter: I) -> PeekableOwned<I, T> {
+        PeekableOwned { iter, peeked: None }
+    }
+}
+
+impl<I, T> PeekableOwned<I, T>
+where
+    I: LendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    /// Returns a reference to the `next()` value without advancing the iterator.
+    ///
+    /// See [`Peekable::peek`](core::iter::Peekable::peek).
+    #[inline]
+    pub fn peek(&mut self) -> Option<&T> {
+        let iter = &mut self.iter;
+        self.peeked
+            .get_or_insert_with(|| iter.next().into_option())
+            .as_ref()
+    }
+
+    /// Returns a mutable reference to the `next()` value without advancing the iterator.
+    ///
+    /// See [`Peekable::peek_mut`](core::iter::Peekable::peek_mut).
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        let iter = &mut self.iter;
+        self.peeked
+            .get_or_insert_with(|| iter.next().into_option())
+            .as_mut()
+    }
+
+    /// Consume and return the next value of this iterator if a condition is true.
+    ///
+    /// See [`Peekable::next_if`](core::iter::Peekable::next_if).
+    pub fn next_if(&mut self, func: impl FnOnce(&T) -> bool) -> Option<T> {
+        match self.next() {
+            Some(matched) if func(&matched) => Some(matched),
+            other => {
+                self.peeked = Some(other);
+                None
+            }
+        }
+    }
+
+    /// Consume and return the next item if it is equal to `expected`.
+    ///
+    /// See [`Peekable::next_if_eq`](core::iter::Peekable::next_if_eq).
+    pub fn next_if_eq<U>(&mut self, expected: &U) -> Option<T>
+    where
+        U: ?Sized,
+        T: PartialEq<U>,
+    {
+        self.next_if(|next| next == expected)
+    }
+}
+
+impl<I, T> LendingIterator for PeekableOwned<I, T>
+where
+    I: LendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    type Item<'a> = T where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.iter.next().into_option(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peek_len = match self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lo, hi) = self.iter.size_hint();
+        (
+            lo.saturating_add(peek_len),
+            hi.and_then(|hi| hi.checked_add(peek_len)),
+        )
+    }
+
+    #[inline]
+    fn count(self) -> usize
+    where
+        Self: Sized,
+    {
+        match self.peeked {
+            Some(None) => 0,
+            Some(Some(_)) => 1 + self.iter.count(),
+            None => self.iter.count(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        match self.peeked.take() {
+            Some(None) => None,
+            Some(v @ Some(_)) if n == 0 => v,
+            Some(Some(_)) => self.iter.nth(n - 1).into_option(),
+            None => self.iter.nth(n).into_option(),
+        }
+    }
+
+    #[inline]
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(B, T) -> B,
+    {
+        let mut acc = match self.peeked {
+            Some(None) => return init,
+            Some(Some(v)) => f(init, v),
+            None => init,
+        };
+        while let Some(x) = self.iter.next().into_option() {
+            acc = f(acc, x);
+        }
+        acc
+    }
+}
+
+impl<I, T> DoubleEndedLendingIterator for PeekableOwned<I, T>
+where
+    I: DoubleEndedLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        match self.peeked.as_mut() {
+            Some(v @ Some(_)) => self.iter.next_back().into_option().or_else(|| v.take()),
+            Some(None) => None,
+            None => self.iter.next_back().into_option(),
+        }
+    }
+}
+
+impl<I, T> ExactSizeLendingIterator for PeekableOwned<I, T>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+}
+
+impl<I, T> FusedLendingIterator for PeekableOwned<I, T>
+where
+    I: FusedLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DoubleEndedLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut iter = (0..5).into_lending().peekable_owned();
+        assert_eq!(iter.peek(), Some(&0));
+        assert_eq!(iter.next_if_eq(&1), None);
+        assert_eq!(iter.next_if_eq(&0), Some(0));
+        if let Some(x) = iter.peek_mut() {
+            *x = 10;
+        }
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_if(|x| *x > 5), Some(10));
+        assert_eq!(iter.nth(1), Some(3));
+        assert_eq!(iter.peek(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut tokens = ["let", "x", "=", "1"]
+            .into_lending()
+            .zip((0..4).into_lending())
+            .peekable_owned();
+        assert_eq!(tokens.peek(), Some(&("let", 0)));
+        assert_eq!(tokens.fold(0, |acc, (_, i)| acc + i), 6);
+    }
+}
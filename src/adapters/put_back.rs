@@ -0,0 +1,85 @@
+use crate::{ExactSizeLendingIterator, LendingIterator, OptionTrait};
+
+/// A lending iterator whose items don't borrow from it, which allows putting back a
+/// single element.
+///
+/// This `struct` is created by the [`put_back_owned`] method on [`LendingIterator`].
+/// See its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`put_back_owned`]: crate::LendingIterator::put_back_owned
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PutBack<I, T> {
+    iter: I,
+    top: Option<T>,
+}
+
+impl<I, T> PutBack<I, T> {
+    pub(crate) fn new(iter: I) -> PutBack<I, T> {
+        PutBack { iter, top: None }
+    }
+
+    /// Puts back an element in front of the lending iterator, so that it is yielded by
+    /// the next call to [`next`](LendingIterator::next).
+    ///
+    /// Only one element can be put back at a time, so this returns the element that was
+    /// put back previously and not yielded yet, if any.
+    #[inline]
+    pub fn put_back(&mut self, item: T) -> Option<T> {
+        self.top.replace(item)
+    }
+}
+
+impl<I, T> LendingIterator for PutBack<I, T>
+where
+    I: LendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+    type Item<'a> = T where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        match self.top.take() {
+            Some(top) => Some(top),
+            None => self.iter.next().into_option(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let top_len = usize::from(self.top.is_some());
+        let (lo, hi) = self.iter.size_hint();
+        (
+            lo.saturating_add(top_len),
+            hi.and_then(|hi| hi.checked_add(top_len)),
+        )
+    }
+}
+
+impl<I, T> ExactSizeLendingIterator for PutBack<I, T>
+where
+    I: ExactSizeLendingIterator,
+    for<'a> Option<I::Item<'a>>: OptionTrait<Item = T>,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut iter = ["(", "a", ")"].into_lending().put_back_owned();
+        let open = iter.next().unwrap();
+        assert_eq!(iter.put_back(open), None);
+        assert_eq!(iter.put_back("["), Some("("));
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some("["));
+        assert_eq!(iter.next(), Some("a"));
+        iter.put_back("b");
+        assert_eq!(iter.next(), Some("b"));
+        assert_eq!(iter.next(), Some(")"));
+        assert_eq!(iter.next(), None);
+    }
+}
@@ -0,0 +1,174 @@
+use core::num::NonZeroUsize;
+
+use stable_try_trait_v2::Try;
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+};
+
+/// A double-ended lending iterator with the direction inverted.
+///
+/// This `struct` is created by the [`rev`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`rev`]: crate::LendingIterator::rev
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Rev<I> {
+    iter: I,
+}
+
+impl<I> Rev<I> {
+    pub(crate) fn new(iter: I) -> Rev<I> {
+        Rev { iter }
+    }
+}
+
+impl<I> LendingIterator for Rev<I>
+where
+    I: DoubleEndedLendingIterator,
+{
+    type Item<'a> = I::Item<'a> where I: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.next_back()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn advance_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_back_by(n)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        self.iter.nth_back(n)
+    }
+
+    #[inline]
+    fn try_fold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, I::Item<'all>) -> R,
+        R: Try<Output = B>,
+    {
+        self.iter.try_rfold(init, f)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, I::Item<'all>) -> B,
+    {
+        self.iter.rfold(init, f)
+    }
+}
+
+impl<I> DoubleEndedLendingIterator for Rev<I>
+where
+    I: DoubleEndedLendingIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        self.iter.advance_by(n)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        self.iter.nth(n)
+    }
+
+    #[inline]
+    fn try_rfold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, I::Item<'all>) -> R,
+        R: Try<Output = B>,
+    {
+        self.iter.try_fold(init, f)
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, I::Item<'all>) -> B,
+    {
+        self.iter.fold(init, f)
+    }
+}
+
+impl<I> ExactSizeLendingIterator for Rev<I>
+where
+    I: DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I> FusedLendingIterator for Rev<I> where I: DoubleEndedLendingIterator + FusedLendingIterator {}
+
+#[cfg(test)]
+mod test {
+    use crate::{DoubleEndedLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let mut xs = Vec::new();
+        (0..5).into_lending().rev().for_each(|x| xs.push(x));
+        assert_eq!(xs, (0..5).rev().collect::<Vec<_>>());
+        assert_eq!((0..5).into_lending().rev().nth(1), (0..5).rev().nth(1));
+        assert_eq!(
+            (0..5).into_lending().rev().next_back(),
+            (0..5).rev().next_back()
+        );
+        assert_eq!(
+            (0..5).into_lending().rev().rfold(0, |acc, x| acc * 10 + x),
+            (0..5).rev().rfold(0, |acc, x| acc * 10 + x)
+        );
+    }
+
+    #[test]
+    fn test_pipeline() {
+        let mut xs = Vec::new();
+        (0..4)
+            .into_lending()
+            .chain((4..8).into_lending())
+            .filter(|x| x % 3 != 0)
+            .map(|x| x * 2)
+            .rev()
+            .for_each(|x| xs.push(x));
+        assert_eq!(
+            xs,
+            (0..4)
+                .chain(4..8)
+                .filter(|x| x % 3 != 0)
+                .map(|x| x * 2)
+                .rev()
+                .collect::<Vec<_>>()
+        );
+
+        let mut refs = vec![String::from("a"), String::from("b")].lend_refs_mut();
+        refs.next_back().unwrap().push('!');
+        assert_eq!(refs.next_back().map(|s| s.as_str()), Some("a"));
+        assert_eq!(refs.next(), None);
+        assert_eq!(
+            vec![1, 2, 3].lend_refs().rfind(|x| **x < 3).copied(),
+            Some(2)
+        );
+        assert_eq!((0..5).into_lending().rposition(|x| x == 1), Some(1));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{LendingIterator, OptionTrait, SingleArgFnMut, SingleArgFnOnce};
+
+/// A lending iterator to maintain state while iterating another lending iterator.
+///
+/// This `struct` is created by the [`scan`] method on [`LendingIterator`]. See
+/// its documentation for more.
+///
+/// [`LendingIterator`]: crate::LendingIterator
+/// [`scan`]: crate::LendingIterator::scan
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Scan<I, St, F> {
+    iter: I,
+    state: St,
+    f: F,
+}
+
+impl<I, St, F> Scan<I, St, F> {
+    pub(crate) fn new(iter: I, state: St, f: F) -> Self {
+        Self { iter, state, f }
+    }
+}
+
+impl<I, St, F> LendingIterator for Scan<I, St, F>
+where
+    I: LendingIterator,
+    F: for<'a> SingleArgFnMut<(&'a mut St, I::Item<'a>)>,
+    for<'a> <F as SingleArgFnOnce<(&'a mut St, I::Item<'a>)>>::Output: OptionTrait,
+{
+    type Item<'a> = <<F as SingleArgFnOnce<(&'a mut St, I::Item<'a>)>>::Output as OptionTrait>::Item
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let item = self.iter.next()?;
+        (self.f)((&mut self.state, item)).into_option()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the scan function
+    }
+}
+
+/// An iterator to maintain state while iterating a lending iterator.
+///
+/// This `struct` is created when [`IntoIterator::into_iter`] is called on [`Scan`].
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoIter<I, St, F> {
+    iter: I,
+    state: St,
+    f: F,
+}
+
+impl<I, St, F, O> Iterator for IntoIter<I, St, F>
+where
+    I: LendingIterator,
+    F: FnMut((&mut St, I::Item<'_>)) -> Option<O>,
+{
+    type Item = O;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        (self.f)((&mut self.state, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, St, F, O> IntoIterator for Scan<I, St, F>
+where
+    I: LendingIterator,
+    F: FnMut((&mut St, I::Item<'_>)) -> Option<O>,
+{
+    type Item = O;
+    type IntoIter = IntoIter<I, St, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.iter,
+            state: self.state,
+            f: self.f,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    // lends the window with the largest sum so far, until that sum exceeds 10
+    fn best_window<'a>((best, w): (&'a mut Vec<usize>, &[usize])) -> Option<&'a [usize]> {
+        if best.iter().sum::<usize>() > 10 {
+            return None;
+        }
+        if w.iter().sum::<usize>() > best.iter().sum() {
+            best.clear();
+            best.extend_from_slice(w);
+        }
+        Some(best)
+    }
+
+    #[test]
+    fn test() {
+        let std = (1..10).scan(1, |state, x| {
+            *state *= x;
+            (*state < 1000).then_some(*state)
+        });
+        let lending = (1..10)
+            .into_lending()
+            .scan(1, |(state, x): (&mut i32, i32)| {
+                *state *= x;
+                (*state < 1000).then_some(*state)
+            });
+        assert_eq!(
+            lending.into_iter().collect::<Vec<_>>(),
+            std.collect::<Vec<_>>()
+        );
+
+        // the lent items can borrow from the state
+        let mut sums = Vec::new();
+        let mut bests = [3, 1, 4, 1, 5, 9, 2]
+            .windows(2)
+            .scan(Vec::new(), best_window);
+        while let Some(best) = bests.next() {
+            sums.push(best.iter().sum::<usize>());
+        }
+        assert_eq!(sums, vec![4, 5, 5, 6, 14]);
+    }
+}
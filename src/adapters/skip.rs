@@ -1,4 +1,6 @@
-use crate::LendingIterator;
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+};
 
 /// A lending iterator that skips over the first `n` items of `iter`.
 #[derive(Clone, Debug)]
@@ -37,6 +39,36 @@ where
     }
 }
 
+impl<I> DoubleEndedLendingIterator for Skip<I>
+where
+    I: DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        if self.iter.len() > self.n {
+            self.iter.next_back()
+        } else {
+            None
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        let len = self.iter.len().saturating_sub(self.n);
+        if n < len {
+            self.iter.nth_back(n)
+        } else {
+            if len > 0 {
+                // consume the remaining (unskipped) elements from the back
+                let _ = self.iter.advance_back_by(len);
+            }
+            None
+        }
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Skip<I> {}
+
+impl<I: FusedLendingIterator> FusedLendingIterator for Skip<I> {}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -45,4 +77,18 @@ mod test {
     fn test() {
         assert_eq!((0..5).into_lending().skip(1).nth(1), (0..5).skip(1).nth(1));
     }
+
+    #[test]
+    fn test_back() {
+        let mut iter = (0..5).skip(2);
+        let mut lending = (0..5).into_lending().skip(2);
+        assert_eq!(iter.next_back(), lending.next_back());
+        assert_eq!(iter.nth_back(1), lending.nth_back(1));
+        assert_eq!(iter.next_back(), lending.next_back());
+        assert_eq!(iter.next(), lending.next());
+        assert_eq!(
+            (0..5).into_lending().skip(2).nth_back(5),
+            (0..5).skip(2).nth_back(5)
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{FusedLendingIterator, LendingIterator};
 
 use core::fmt;
 
@@ -28,7 +28,7 @@ impl<I: fmt::Debug, P> fmt::Debug for SkipWhile<I, P> {
         f.debug_struct("SkipWhile")
             .field("iter", &self.iter)
             .field("flag", &self.flag)
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -58,3 +58,29 @@ where
         (0, upper) // can't know a lower bound, due to the predicate
     }
 }
+
+impl<I: FusedLendingIterator, P> FusedLendingIterator for SkipWhile<I, P> where
+    for<'all> P: FnMut(&I::Item<'all>) -> bool
+{
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+    #[test]
+    fn test() {
+        let mut xs = Vec::new();
+        [1, 3, 4, 5, 6]
+            .into_lending()
+            .skip_while(|x| x % 2 == 1)
+            .inspect(|x| assert!(*x > 3))
+            .for_each(|x| xs.push(x));
+        assert_eq!(
+            xs,
+            [1, 3, 4, 5, 6]
+                .into_iter()
+                .skip_while(|x| x % 2 == 1)
+                .collect::<Vec<_>>()
+        );
+    }
+}
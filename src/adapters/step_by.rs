@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{ExactSizeLendingIterator, FusedLendingIterator, LendingIterator};
 
 /// A lending iterator for stepping lending iterators by a custom amount.
 ///
@@ -43,4 +43,48 @@ where
             self.iter.nth(self.step)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        // `self.step` is one less than the step passed to `step_by`
+        let step = self.step + 1;
+        let size = |n: usize| {
+            if self.first_take {
+                if n == 0 {
+                    0
+                } else {
+                    1 + (n - 1) / step
+                }
+            } else {
+                n / step
+            }
+        };
+        (size(lower), upper.map(size))
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for StepBy<I> {}
+
+impl<I: FusedLendingIterator> FusedLendingIterator for StepBy<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+    #[test]
+    fn test_size_hint() {
+        for len in 0..6 {
+            for step in 1..4 {
+                let mut iter = (0..len).step_by(step);
+                let mut lending = (0..len).into_lending().step_by(step);
+                loop {
+                    assert_eq!(iter.size_hint(), lending.size_hint());
+                    let (a, b) = (iter.next(), lending.next());
+                    assert_eq!(a, b);
+                    if a.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
 }
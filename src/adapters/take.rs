@@ -1,4 +1,7 @@
-use crate::LendingIterator;
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator,
+    HasNextLendingIterator, LendingIterator,
+};
 
 /// A Lending iterator that only lends the first `n` iterations of `iter`.
 #[derive(Clone, Debug)]
@@ -32,6 +35,46 @@ where
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+
+        let lower = core::cmp::min(lower, self.n);
+        let upper = match upper {
+            Some(x) if x < self.n => Some(x),
+            _ => Some(self.n),
+        };
+        (lower, upper)
+    }
+}
+
+impl<I> DoubleEndedLendingIterator for Take<I>
+where
+    I: DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        if self.n == 0 {
+            None
+        } else {
+            let n = self.n;
+            self.n -= 1;
+            let skip = self.iter.len().saturating_sub(n);
+            self.iter.nth_back(skip)
+        }
+    }
+}
+
+impl<I: ExactSizeLendingIterator> ExactSizeLendingIterator for Take<I> {}
+
+impl<I: FusedLendingIterator> FusedLendingIterator for Take<I> {}
+
+impl<I: HasNextLendingIterator> HasNextLendingIterator for Take<I> {
+    fn has_next(&mut self) -> bool {
+        self.n != 0 && self.iter.has_next()
+    }
 }
 
 #[cfg(test)]
@@ -48,4 +91,29 @@ mod test {
             5
         );
     }
+
+    #[test]
+    fn test_size_hint() {
+        for n in 0..4 {
+            let mut iter = (0..2).take(n);
+            let mut lending = (0..2).into_lending().take(n);
+            assert_eq!(iter.size_hint(), lending.size_hint());
+            assert_eq!(iter.next(), lending.next());
+            assert_eq!(iter.size_hint(), lending.size_hint());
+        }
+        assert_eq!(
+            core::iter::repeat(()).into_lending().take(3).size_hint(),
+            (3, Some(3))
+        );
+    }
+
+    #[test]
+    fn test_back() {
+        let mut iter = (0..10).take(4);
+        let mut lending = (0..10).into_lending().take(4);
+        assert_eq!(iter.next_back(), lending.next_back());
+        assert_eq!(iter.next(), lending.next());
+        assert_eq!(iter.nth_back(1), lending.nth_back(1));
+        assert_eq!(iter.next_back(), lending.next_back());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::LendingIterator;
+use crate::{FusedLendingIterator, LendingIterator};
 
 /// A lending iterator that yields items based on a predicate.
 ///
@@ -50,3 +50,10 @@ where
         }
     }
 }
+
+impl<I, P> FusedLendingIterator for TakeWhile<I, P>
+where
+    I: FusedLendingIterator,
+    P: for<'a> FnMut(&I::Item<'a>) -> bool,
+{
+}
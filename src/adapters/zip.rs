@@ -1,4 +1,7 @@
-use crate::LendingIterator;
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator,
+    HasNextLendingIterator, LendingIterator,
+};
 
 /// A lending iterator that iterates two other lending iterators simultaneously.
 ///
@@ -34,4 +37,96 @@ where
         let b = self.b.next()?;
         Some((a, b))
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+
+        let lower = core::cmp::min(a_lower, b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(core::cmp::min(x, y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A, B> DoubleEndedLendingIterator for Zip<A, B>
+where
+    A: DoubleEndedLendingIterator + ExactSizeLendingIterator,
+    B: DoubleEndedLendingIterator + ExactSizeLendingIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        let a_len = self.a.len();
+        let b_len = self.b.len();
+        // trim the longer side so that both ends line up
+        if a_len > b_len {
+            let _ = self.a.advance_back_by(a_len - b_len);
+        } else if b_len > a_len {
+            let _ = self.b.advance_back_by(b_len - a_len);
+        }
+        let a = self.a.next_back()?;
+        let b = self.b.next_back()?;
+        Some((a, b))
+    }
+}
+
+impl<A, B> ExactSizeLendingIterator for Zip<A, B>
+where
+    A: ExactSizeLendingIterator,
+    B: ExactSizeLendingIterator,
+{
+}
+
+impl<A, B> FusedLendingIterator for Zip<A, B>
+where
+    A: FusedLendingIterator,
+    B: FusedLendingIterator,
+{
+}
+
+impl<A, B> HasNextLendingIterator for Zip<A, B>
+where
+    A: HasNextLendingIterator,
+    B: HasNextLendingIterator,
+{
+    fn has_next(&mut self) -> bool {
+        self.a.has_next() && self.b.has_next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DoubleEndedLendingIterator, LendingIterator, ToLendingIterator};
+    #[test]
+    fn test_back() {
+        let mut iter = (0..3).zip(0..5);
+        let mut lending = (0..3).into_lending().zip((0..5).into_lending());
+        assert_eq!(iter.next_back(), lending.next_back());
+        assert_eq!(iter.next(), lending.next());
+        assert_eq!(iter.next_back(), lending.next_back());
+        assert_eq!(iter.next_back(), lending.next_back());
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let mut iter = (0..3).zip(core::iter::repeat(0));
+        let mut lending = (0..3)
+            .into_lending()
+            .zip(core::iter::repeat(0).into_lending());
+        assert_eq!(iter.size_hint(), lending.size_hint());
+        assert_eq!(iter.next(), lending.next());
+        assert_eq!(iter.size_hint(), lending.size_hint());
+        assert_eq!(
+            core::iter::repeat(0)
+                .into_lending()
+                .zip((0..4).into_lending().filter(|_| true))
+                .size_hint(),
+            core::iter::repeat(0)
+                .zip((0..4).filter(|_| true))
+                .size_hint()
+        );
+    }
 }
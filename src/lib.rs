@@ -86,9 +86,11 @@
 extern crate alloc;
 
 mod adapters;
+mod sources;
 mod to_lending;
 mod traits;
 pub use self::adapters::*;
+pub use self::sources::*;
 pub use self::to_lending::*;
 pub use self::traits::*;
 
@@ -134,4 +136,126 @@ mod tests {
                 println!("{a:?}, {b:?}");
             });
     }
+
+    #[test]
+    fn comparisons() {
+        use core::cmp::Ordering;
+
+        let golden = vec![vec![1, 2], vec![2, 3], vec![3, 4]];
+        assert!((1..5).windows(2).eq(golden.clone().into_lending()));
+        assert!(!(1..5).windows(2).ne(golden.into_lending()));
+        assert!((1..5).windows(2).ne((1..6).windows(2)));
+        assert_eq!((1..5).windows(2).cmp((1..6).windows(2)), Ordering::Less);
+        assert_eq!((1..6).windows(2).cmp((1..5).windows(2)), Ordering::Greater);
+        assert_eq!((2..5).windows(2).cmp((1..6).windows(2)), Ordering::Greater);
+        assert_eq!(
+            ["a", "b"]
+                .into_lending()
+                .cmp::<_, str>(vec![String::from("a")].into_lending()),
+            Ordering::Greater
+        );
+        assert!((1..5).windows(2).lt((1..6).windows(2)));
+        assert!((1..5).windows(2).le((1..5).windows(2)));
+        assert!((2..4).windows(2).gt((1..6).windows(2)));
+        assert!((2..4).windows(2).ge((2..4).windows(2)));
+
+        let nan = [1.0, f64::NAN];
+        assert_eq!(nan.into_lending().partial_cmp(nan.into_lending()), None);
+        assert!(!nan.into_lending().eq(nan.into_lending()));
+        assert!((1..5)
+            .windows(2)
+            .eq_by((0..3).into_lending(), |a: &[i32], b| a[0] == b + 1));
+        assert_eq!(
+            (1..5)
+                .windows(2)
+                .cmp_by((1..4).into_lending(), |a: &[i32], b| a[1].cmp(&b)),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn queries() {
+        let xs = [1, 3, 4, 6, 7, 9];
+        let odd = |x: i32| x % 2 == 1;
+        let big = |x: i32| x > 5;
+        assert_eq!(xs.into_lending().all(odd), xs.into_iter().all(odd));
+        assert_eq!(xs.into_lending().any(big), xs.into_iter().any(big));
+        assert_eq!(
+            xs.into_lending().all(|x| x > 0),
+            xs.into_iter().all(|x| x > 0)
+        );
+        assert_eq!(
+            xs.into_lending().any(|x| x > 9),
+            xs.into_iter().any(|x| x > 9)
+        );
+        assert_eq!(
+            xs.into_lending().position(big),
+            xs.into_iter().position(big)
+        );
+        assert_eq!(
+            xs.into_lending().position(|x| x > 9),
+            xs.into_iter().position(|x| x > 9)
+        );
+        assert_eq!(
+            xs.into_lending().rposition(odd),
+            xs.into_iter().rposition(odd)
+        );
+        assert!(xs.into_lending().is_partitioned(|x| x < 5));
+        assert!(xs.into_lending().is_partitioned(|x| x > 0));
+        assert!(!xs.into_lending().is_partitioned(odd));
+        assert!(!xs.into_lending().is_partitioned(big));
+
+        let mut iter = xs.into_lending();
+        assert_eq!(iter.position(|x| x == 4), Some(2));
+        assert_eq!(iter.position(|x| x == 7), Some(1));
+        assert!(!iter.any(|x| x == 4));
+
+        assert!((0..6).windows(3).all(|w| w[0] < w[2]));
+        assert!((0..6).windows(3).any(|w| w.iter().sum::<usize>() == 9));
+        assert_eq!((0..6).windows(3).position(|w| w[1] == 3), Some(2));
+        assert!((0..6).windows(2).is_partitioned(|w| w[0] < 3));
+    }
+
+    #[test]
+    fn extrema() {
+        let scores = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3];
+        let sum = |w: &&[i32]| w.iter().sum::<i32>();
+        assert_eq!(scores.windows(3).max_by_key(sum), Some((5, 17)));
+        assert_eq!(scores.windows(3).min_by_key(sum), Some((1, 6)));
+        assert_eq!(
+            scores.into_lending().max_by_key(|x| x % 5),
+            scores
+                .into_iter()
+                .enumerate()
+                .max_by_key(|(_, x)| x % 5)
+                .map(|(i, x)| (i, x % 5))
+        );
+        assert_eq!(
+            scores.into_lending().min_by_key(|x| x % 5),
+            scores
+                .into_iter()
+                .enumerate()
+                .min_by_key(|(_, x)| x % 5)
+                .map(|(i, x)| (i, x % 5))
+        );
+        assert_eq!(
+            core::iter::empty::<i32>().into_lending().max_by_key(|x| *x),
+            None
+        );
+
+        assert_eq!(scores.windows(3).max_owned(), Some(vec![9, 2, 6]));
+        assert_eq!(scores.windows(3).min_owned(), Some(vec![1, 4, 1]));
+        assert_eq!(scores.lend_refs().max_owned(), scores.into_iter().max());
+        assert_eq!(scores.lend_refs().min_owned(), scores.into_iter().min());
+        assert_eq!(scores.windows(11).max_owned(), None);
+
+        assert_eq!(
+            scores.windows(2).reduce_owned(|mut acc: Vec<i32>, w| {
+                acc.push(w[1]);
+                acc
+            }),
+            Some(scores.to_vec())
+        );
+        assert_eq!(scores.lend_refs().reduce_owned(|a, b| a + b), Some(39));
+    }
 }
@@ -0,0 +1,84 @@
+use core::{fmt, marker::PhantomData};
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, ForLt, FusedLendingIterator,
+    LendingIterator,
+};
+
+/// Creates a lending iterator that yields nothing.
+///
+/// Its items are named by the [`ForLt`] `L`, so it can stand in for lending iterators
+/// whose items borrow from them.
+///
+/// See [`core::iter::empty`].
+///
+/// ```
+/// # use gat_lending_iterator::{empty, LendingIterator, Ref, ToLendingIterator};
+/// let mut iter = empty::<Ref<[i32]>>().chain((0..3).windows(2));
+/// assert_eq!(iter.next(), Some([0, 1].as_slice()));
+/// ```
+pub fn empty<L: ForLt + ?Sized>() -> Empty<L> {
+    Empty(PhantomData)
+}
+
+/// A lending iterator that yields nothing.
+///
+/// This `struct` is created by the [`empty()`] function. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Empty<L: ?Sized>(PhantomData<fn() -> L>);
+
+impl<L: ?Sized> Clone for Empty<L> {
+    fn clone(&self) -> Self {
+        Empty(PhantomData)
+    }
+}
+
+impl<L: ?Sized> Default for Empty<L> {
+    fn default() -> Self {
+        Empty(PhantomData)
+    }
+}
+
+impl<L: ?Sized> fmt::Debug for Empty<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Empty")
+    }
+}
+
+impl<L: ForLt + ?Sized> LendingIterator for Empty<L> {
+    type Item<'a> = L::Of<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<L::Of<'_>> {
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+impl<L: ForLt + ?Sized> DoubleEndedLendingIterator for Empty<L> {
+    #[inline]
+    fn next_back(&mut self) -> Option<L::Of<'_>> {
+        None
+    }
+}
+
+impl<L: ForLt + ?Sized> ExactSizeLendingIterator for Empty<L> {}
+
+impl<L: ForLt + ?Sized> FusedLendingIterator for Empty<L> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{empty, ExactSizeLendingIterator, LendingIterator, Owned, RefMut};
+
+    #[test]
+    fn test() {
+        let mut iter = empty::<RefMut<[u8]>>();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(empty::<Owned<i32>>().fold(0, |acc, x| acc + x), 0);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::{LendingIterator, OptionTrait, SingleArgFnMut, SingleArgFnOnce};
+
+/// Creates a lending iterator that calls `f` with a mutable reference to `state` to
+/// produce each element, which may borrow from the state.
+///
+/// As of writing, in stable rust it's not possible to create a closure
+/// where the lifetime of its output is tied to its input.
+/// If you're on nightly, you can use the unstable
+/// `closure_lifetime_binder` feature. If you're on stable, try using
+/// a function.
+///
+/// See [`core::iter::from_fn`].
+///
+/// ```
+/// # use gat_lending_iterator::{from_state, LendingIterator};
+/// fn grow(state: &mut (Vec<i32>, usize)) -> Option<&[i32]> {
+///     state.1 += 1;
+///     state.0.get(..state.1)
+/// }
+///
+/// let mut prefixes = from_state((vec![1, 2, 3], 0), grow);
+/// assert_eq!(prefixes.next(), Some([1].as_slice()));
+/// assert_eq!(prefixes.next(), Some([1, 2].as_slice()));
+/// assert_eq!(prefixes.next(), Some([1, 2, 3].as_slice()));
+/// assert_eq!(prefixes.next(), None);
+/// ```
+pub fn from_state<S, F>(state: S, f: F) -> FromState<S, F>
+where
+    for<'all> F: SingleArgFnMut<&'all mut S>,
+    for<'all> <F as SingleArgFnOnce<&'all mut S>>::Output: OptionTrait,
+{
+    FromState { state, f }
+}
+
+/// A lending iterator that produces each element from a mutable reference to its state.
+///
+/// This `struct` is created by the [`from_state()`] function. See its documentation for
+/// more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FromState<S, F> {
+    state: S,
+    f: F,
+}
+
+impl<S, F> FromState<S, F> {
+    /// Consumes the lending iterator, returning its state.
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S, F> LendingIterator for FromState<S, F>
+where
+    for<'a> F: SingleArgFnMut<&'a mut S>,
+    for<'a> <F as SingleArgFnOnce<&'a mut S>>::Output: OptionTrait,
+{
+    type Item<'a> = <<F as SingleArgFnOnce<&'a mut S>>::Output as OptionTrait>::Item
+    where
+        Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        (self.f)(&mut self.state).into_option()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{from_state, LendingIterator};
+
+    fn next_line<'a>(state: &'a mut (&'static str, String)) -> Option<&'a mut String> {
+        let (rest, line) = state;
+        let (first, tail) = rest.split_once('\n').unwrap_or((rest, ""));
+        if first.is_empty() && tail.is_empty() {
+            return None;
+        }
+        line.clear();
+        line.push_str(first);
+        *rest = tail;
+        Some(line)
+    }
+
+    #[test]
+    fn test() {
+        let mut lines = from_state(("a\nbc\ndef", String::new()), next_line);
+        let mut lens = Vec::new();
+        while let Some(line) = lines.next() {
+            line.push('!');
+            lens.push(line.len());
+        }
+        assert_eq!(lens, [2, 3, 4]);
+        assert_eq!(lines.into_inner(), ("", String::from("def!")));
+
+        let mut counter = from_state(0, |n: &mut i32| {
+            *n += 1;
+            (*n < 3).then_some(*n)
+        });
+        assert_eq!(counter.by_ref().count(), 2);
+        assert_eq!(counter.into_inner(), 3);
+    }
+}
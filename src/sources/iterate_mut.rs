@@ -0,0 +1,165 @@
+use crate::{FusedLendingIterator, LendingIterator};
+
+/// Creates a lending iterator that endlessly applies `step` to `state` in place, and
+/// lends a reference to the updated state after each step.
+///
+/// Unlike [`core::iter::successors`], the state is never cloned, so it can be observed
+/// between steps through the rest of the adapters.
+///
+/// ```
+/// # use gat_lending_iterator::{iterate_mut, LendingIterator};
+/// let mut ticks = iterate_mut(vec![1], |v: &mut Vec<i32>| v.push(v.len() as i32 + 1));
+/// assert_eq!(ticks.next(), Some(&vec![1, 2]));
+/// assert_eq!(ticks.nth(1), Some(&vec![1, 2, 3, 4]));
+/// ```
+pub fn iterate_mut<S, F>(state: S, step: F) -> IterateMut<S, F>
+where
+    F: FnMut(&mut S),
+{
+    IterateMut { state, step }
+}
+
+/// A lending iterator that applies a step to its state in place, and lends references
+/// to it.
+///
+/// This `struct` is created by the [`iterate_mut()`] function. See its documentation
+/// for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterateMut<S, F> {
+    state: S,
+    step: F,
+}
+
+impl<S, F> IterateMut<S, F> {
+    /// Consumes the lending iterator, returning its state.
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S, F> LendingIterator for IterateMut<S, F>
+where
+    F: FnMut(&mut S),
+{
+    type Item<'a> = &'a S where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<&S> {
+        (self.step)(&mut self.state);
+        Some(&self.state)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<S, F> FusedLendingIterator for IterateMut<S, F> where F: FnMut(&mut S) {}
+
+/// Creates a lending iterator that endlessly applies `step` to `state` in place, and
+/// lends a mutable reference to the updated state after each step.
+///
+/// See [`iterate_mut`].
+///
+/// ```
+/// # use gat_lending_iterator::{iterate_lend_mut, LendingIterator};
+/// let mut ticks = iterate_lend_mut([0, 0], |s: &mut [i32; 2]| s[0] += 1);
+/// ticks.next().unwrap()[1] = 5;
+/// assert_eq!(ticks.next(), Some(&mut [2, 5]));
+/// ```
+pub fn iterate_lend_mut<S, F>(state: S, step: F) -> IterateLendMut<S, F>
+where
+    F: FnMut(&mut S),
+{
+    IterateLendMut { state, step }
+}
+
+/// A lending iterator that applies a step to its state in place, and lends mutable
+/// references to it.
+///
+/// This `struct` is created by the [`iterate_lend_mut()`] function. See its
+/// documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IterateLendMut<S, F> {
+    state: S,
+    step: F,
+}
+
+impl<S, F> IterateLendMut<S, F> {
+    /// Consumes the lending iterator, returning its state.
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S, F> LendingIterator for IterateLendMut<S, F>
+where
+    F: FnMut(&mut S),
+{
+    type Item<'a> = &'a mut S where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<&mut S> {
+        (self.step)(&mut self.state);
+        Some(&mut self.state)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<S, F> FusedLendingIterator for IterateLendMut<S, F> where F: FnMut(&mut S) {}
+
+#[cfg(test)]
+mod test {
+    use crate::{iterate_lend_mut, iterate_mut, LendingIterator};
+
+    #[derive(Debug, PartialEq)]
+    struct Sim {
+        tick: u32,
+        pos: i32,
+        vel: i32,
+    }
+
+    impl Sim {
+        fn new(vel: i32) -> Sim {
+            Sim {
+                tick: 0,
+                pos: 0,
+                vel,
+            }
+        }
+    }
+
+    fn tick(sim: &mut Sim) {
+        sim.tick += 1;
+        sim.pos += sim.vel;
+        sim.vel -= 1;
+    }
+
+    #[test]
+    fn test() {
+        let mut sims = iterate_mut(Sim::new(3), tick)
+            .enumerate()
+            .step_by(2)
+            .take(3);
+        assert_eq!(sims.next().map(|(i, s)| (i, s.tick)), Some((0, 1)));
+        assert_eq!(sims.next().map(|(i, s)| (i, s.pos)), Some((2, 6)));
+        assert_eq!(sims.next().map(|(_, s)| s.vel), Some(-2));
+        assert_eq!(sims.next(), None);
+
+        let mut sims = iterate_mut(Sim::new(3), tick);
+        assert_eq!(sims.find(|s| s.pos < 0).map(|s| s.tick), Some(8));
+        assert_eq!(sims.into_inner().vel, -5);
+
+        let mut sims = iterate_lend_mut(Sim::new(0), tick);
+        sims.next().unwrap().vel = 10;
+        assert_eq!(sims.nth(1).map(|s| s.pos), Some(19));
+        assert_eq!(sims.into_inner().tick, 3);
+    }
+}
@@ -0,0 +1,14 @@
+mod empty;
+mod from_state;
+mod iterate_mut;
+mod once_ref;
+mod repeat;
+mod successors_mut;
+pub use self::{
+    empty::{empty, Empty},
+    from_state::{from_state, FromState},
+    iterate_mut::{iterate_lend_mut, iterate_mut, IterateLendMut, IterateMut},
+    once_ref::{once_ref, OnceRef},
+    repeat::{repeat_mut, repeat_ref, RepeatMut, RepeatRef},
+    successors_mut::{successors_mut, StepResult, SuccessorsMut},
+};
@@ -0,0 +1,74 @@
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator, LendingIterator,
+};
+
+/// Creates a lending iterator that lends a reference to `value` exactly once.
+///
+/// See [`core::iter::once`].
+///
+/// ```
+/// # use gat_lending_iterator::{once_ref, LendingIterator};
+/// let mut iter = once_ref(String::from("once"));
+/// assert_eq!(iter.next().map(String::as_str), Some("once"));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn once_ref<T>(value: T) -> OnceRef<T> {
+    OnceRef { value, lent: false }
+}
+
+/// A lending iterator that lends a reference to a value exactly once.
+///
+/// This `struct` is created by the [`once_ref()`] function. See its documentation for
+/// more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OnceRef<T> {
+    value: T,
+    lent: bool,
+}
+
+impl<T> LendingIterator for OnceRef<T> {
+    type Item<'a> = &'a T where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<&T> {
+        if self.lent {
+            None
+        } else {
+            self.lent = true;
+            Some(&self.value)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::from(!self.lent);
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedLendingIterator for OnceRef<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&T> {
+        self.next()
+    }
+}
+
+impl<T> ExactSizeLendingIterator for OnceRef<T> {}
+
+impl<T> FusedLendingIterator for OnceRef<T> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{once_ref, DoubleEndedLendingIterator, ExactSizeLendingIterator, LendingIterator};
+
+    #[test]
+    fn test() {
+        let mut iter = once_ref(vec![1, 2]);
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next_back(), Some(&vec![1, 2]));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(once_ref(3).map(|x: &i32| x * 2).sum::<i32>(), 6);
+    }
+}
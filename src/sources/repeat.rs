@@ -0,0 +1,135 @@
+use crate::{DoubleEndedLendingIterator, FusedLendingIterator, LendingIterator};
+
+/// Creates a lending iterator that endlessly lends references to `value`.
+///
+/// Unlike [`core::iter::repeat`], the value is never cloned.
+///
+/// ```
+/// # use gat_lending_iterator::{repeat_ref, LendingIterator};
+/// let mut iter = repeat_ref(String::from("again"));
+/// assert_eq!(iter.next().map(String::as_str), Some("again"));
+/// assert_eq!(iter.nth(100).map(String::as_str), Some("again"));
+/// ```
+pub fn repeat_ref<T>(value: T) -> RepeatRef<T> {
+    RepeatRef { value }
+}
+
+/// A lending iterator that endlessly lends references to a value.
+///
+/// This `struct` is created by the [`repeat_ref()`] function. See its documentation for
+/// more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RepeatRef<T> {
+    value: T,
+}
+
+impl<T> LendingIterator for RepeatRef<T> {
+    type Item<'a> = &'a T where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<&T> {
+        Some(&self.value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    #[inline]
+    fn nth(&mut self, _n: usize) -> Option<&T> {
+        Some(&self.value)
+    }
+}
+
+impl<T> DoubleEndedLendingIterator for RepeatRef<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&T> {
+        Some(&self.value)
+    }
+}
+
+impl<T> FusedLendingIterator for RepeatRef<T> {}
+
+/// Creates a lending iterator that endlessly lends mutable references to `value`.
+///
+/// Every element is the same value, so changes made through one element are seen by
+/// the next.
+///
+/// ```
+/// # use gat_lending_iterator::{repeat_mut, LendingIterator};
+/// let mut iter = repeat_mut(Vec::new());
+/// for i in 0..3 {
+///     iter.next().unwrap().push(i);
+/// }
+/// assert_eq!(iter.into_inner(), [0, 1, 2]);
+/// ```
+pub fn repeat_mut<T>(value: T) -> RepeatMut<T> {
+    RepeatMut { value }
+}
+
+/// A lending iterator that endlessly lends mutable references to a value.
+///
+/// This `struct` is created by the [`repeat_mut()`] function. See its documentation for
+/// more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RepeatMut<T> {
+    value: T,
+}
+
+impl<T> RepeatMut<T> {
+    /// Consumes the lending iterator, returning the value it lends.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> LendingIterator for RepeatMut<T> {
+    type Item<'a> = &'a mut T where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<&mut T> {
+        Some(&mut self.value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    #[inline]
+    fn nth(&mut self, _n: usize) -> Option<&mut T> {
+        Some(&mut self.value)
+    }
+}
+
+impl<T> DoubleEndedLendingIterator for RepeatMut<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&mut T> {
+        Some(&mut self.value)
+    }
+}
+
+impl<T> FusedLendingIterator for RepeatMut<T> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{repeat_mut, repeat_ref, DoubleEndedLendingIterator, LendingIterator};
+
+    #[test]
+    fn test() {
+        let mut iter = repeat_ref([1, 2]);
+        assert_eq!(iter.size_hint(), (usize::MAX, None));
+        assert_eq!(iter.next_back(), Some(&[1, 2]));
+        assert_eq!(repeat_ref(5).take(3).map(|x: &i32| *x).sum::<i32>(), 15);
+
+        let mut counter = repeat_mut(0);
+        for _ in 0..4 {
+            *counter.next().unwrap() += 1;
+        }
+        assert_eq!(counter.nth(10), Some(&mut 4));
+        assert_eq!(counter.into_inner(), 4);
+    }
+}
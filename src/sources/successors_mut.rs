@@ -0,0 +1,129 @@
+use core::ops::ControlFlow;
+
+use crate::{FusedLendingIterator, LendingIterator};
+
+/// Creates a lending iterator that applies `step` to `state` in place, and lends a
+/// reference to the updated state after each step, until `step` returns `false` or
+/// [`ControlFlow::Break`].
+///
+/// See [`core::iter::successors`] and [`iterate_mut`](crate::iterate_mut).
+///
+/// ```
+/// # use gat_lending_iterator::{successors_mut, LendingIterator};
+/// let mut halves = successors_mut(100, |n: &mut i32| {
+///     *n /= 2;
+///     *n > 10
+/// });
+/// assert_eq!(halves.next(), Some(&50));
+/// assert_eq!(halves.next(), Some(&25));
+/// assert_eq!(halves.next(), Some(&12));
+/// assert_eq!(halves.next(), None);
+/// ```
+pub fn successors_mut<S, F, C>(state: S, step: F) -> SuccessorsMut<S, F>
+where
+    F: FnMut(&mut S) -> C,
+    C: StepResult,
+{
+    SuccessorsMut {
+        state,
+        step,
+        done: false,
+    }
+}
+
+/// The result of a step of [`successors_mut`], telling whether to continue.
+pub trait StepResult {
+    /// Returns `true` if the lending iterator should continue.
+    fn should_continue(self) -> bool;
+}
+
+impl StepResult for bool {
+    #[inline]
+    fn should_continue(self) -> bool {
+        self
+    }
+}
+
+impl<B, C> StepResult for ControlFlow<B, C> {
+    #[inline]
+    fn should_continue(self) -> bool {
+        self.is_continue()
+    }
+}
+
+/// A lending iterator that applies a step to its state in place and lends references
+/// to it, until the step tells it to stop.
+///
+/// This `struct` is created by the [`successors_mut()`] function. See its documentation
+/// for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SuccessorsMut<S, F> {
+    state: S,
+    step: F,
+    done: bool,
+}
+
+impl<S, F> SuccessorsMut<S, F> {
+    /// Consumes the lending iterator, returning its state.
+    pub fn into_inner(self) -> S {
+        self.state
+    }
+}
+
+impl<S, F, C> LendingIterator for SuccessorsMut<S, F>
+where
+    F: FnMut(&mut S) -> C,
+    C: StepResult,
+{
+    type Item<'a> = &'a S where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<&S> {
+        if self.done || !(self.step)(&mut self.state).should_continue() {
+            self.done = true;
+            return None;
+        }
+        Some(&self.state)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<S, F, C> FusedLendingIterator for SuccessorsMut<S, F>
+where
+    F: FnMut(&mut S) -> C,
+    C: StepResult,
+{
+}
+
+#[cfg(test)]
+mod test {
+    use core::ops::ControlFlow;
+
+    use crate::{successors_mut, LendingIterator};
+
+    #[test]
+    fn test() {
+        let mut collatz = successors_mut(6, |n: &mut u32| {
+            if *n == 1 {
+                return ControlFlow::Break(());
+            }
+            *n = if *n % 2 == 0 { *n / 2 } else { 3 * *n + 1 };
+            ControlFlow::Continue(())
+        });
+        assert_eq!(collatz.size_hint(), (0, None));
+        assert_eq!(collatz.next(), Some(&3));
+        assert_eq!(collatz.by_ref().count(), 7);
+        assert_eq!(collatz.next(), None);
+        assert_eq!(collatz.size_hint(), (0, Some(0)));
+        assert_eq!(collatz.into_inner(), 1);
+    }
+}
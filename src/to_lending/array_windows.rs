@@ -0,0 +1,283 @@
+use core::{fmt, iter::FusedIterator, mem::MaybeUninit, ptr};
+
+use crate::{
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
+
+/// The inline buffer behind [`ArrayWindows`] and [`ArrayWindowsMut`].
+///
+/// The initialized elements are `start..start + len`, and the current window is the
+/// last `N` of them. The window slides through `2 * N` slots, and the `N - 1` elements
+/// it keeps are moved back to the front when it reaches the end, so each element is
+/// moved at most once.
+struct WindowBuf<T, const N: usize> {
+    // Nested arrays are laid out contiguously, so windows can span both halves.
+    buf: [[MaybeUninit<T>; N]; 2],
+    start: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> WindowBuf<T, N> {
+    const NON_ZERO: () = assert!(N != 0, "window size must be non-zero");
+
+    fn new() -> Self {
+        // Referencing the constant fails to compile when `N` is 0.
+        let () = Self::NON_ZERO;
+        Self {
+            // SAFETY: an array of `MaybeUninit` doesn't need to be initialized.
+            buf: unsafe { MaybeUninit::<[[MaybeUninit<T>; N]; 2]>::uninit().assume_init() },
+            start: 0,
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn as_ptr(&self) -> *const T {
+        self.buf.as_ptr().cast::<T>()
+    }
+
+    #[inline]
+    fn as_mut_ptr(&mut self) -> *mut T {
+        self.buf.as_mut_ptr().cast::<T>()
+    }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        // SAFETY: `start..start + len` is initialized.
+        unsafe { &*ptr::slice_from_raw_parts(self.as_ptr().add(self.start), self.len) }
+    }
+
+    /// Appends `item`, which requires `len < N`.
+    #[inline]
+    fn push(&mut self, item: T) {
+        debug_assert!(self.len < N);
+        if self.start + self.len == 2 * N {
+            let ptr = self.as_mut_ptr();
+            // SAFETY: `start..start + len` is initialized, and `len < N < start`, so
+            // moving it to the front doesn't overlap and stays in bounds.
+            unsafe { ptr::copy_nonoverlapping(ptr.add(self.start), ptr, self.len) };
+            self.start = 0;
+        }
+        // SAFETY: `start + len < 2 * N`, and that slot is uninitialized.
+        unsafe { self.as_mut_ptr().add(self.start + self.len).write(item) };
+        self.len += 1;
+    }
+
+    /// Drops the first element, which requires `len > 0`.
+    #[inline]
+    fn pop_front(&mut self) {
+        debug_assert!(self.len > 0);
+        // SAFETY: `start` is initialized, and is given up before the element is dropped.
+        let first = unsafe { self.as_mut_ptr().add(self.start).read() };
+        self.start += 1;
+        self.len -= 1;
+        drop(first);
+    }
+
+    /// Slides the window forward onto `item`, or fills the first window with it.
+    #[inline]
+    fn slide(&mut self, item: T) -> *mut [T; N] {
+        if self.len == N {
+            self.pop_front();
+        }
+        self.push(item);
+        debug_assert!(self.len == N);
+        // SAFETY: the last `N` elements, which are the whole buffer, are initialized.
+        unsafe { self.as_mut_ptr().add(self.start).cast::<[T; N]>() }
+    }
+}
+
+impl<T, const N: usize> Drop for WindowBuf<T, N> {
+    fn drop(&mut self) {
+        let ptr = ptr::slice_from_raw_parts_mut(
+            // SAFETY: `start` is at most `2 * N`.
+            unsafe { self.as_mut_ptr().add(self.start) },
+            self.len,
+        );
+        // SAFETY: `start..start + len` is initialized, and isn't used again.
+        unsafe { ptr::drop_in_place(ptr) };
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for WindowBuf<T, N> {
+    fn clone(&self) -> Self {
+        let mut buf = Self::new();
+        for item in self.as_slice() {
+            buf.push(item.clone());
+        }
+        buf
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for WindowBuf<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+/// A lending iterator over windows of a constant size, that doesn't allocate.
+///
+/// This `struct` is created by the [`array_windows`] method on [`ToLendingIterator`].
+/// See its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`array_windows`]: crate::ToLendingIterator::array_windows
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    iter: I,
+    buf: WindowBuf<I::Item, N>,
+}
+
+impl<I: Iterator, const N: usize> ArrayWindows<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        let (iter, buf) = fill(iter);
+        Self { iter, buf }
+    }
+}
+
+fn fill<I: Iterator, const N: usize>(mut iter: I) -> (I, WindowBuf<I::Item, N>) {
+    let mut buf = WindowBuf::new();
+    for item in iter.by_ref().take(N - 1) {
+        buf.push(item);
+    }
+    (iter, buf)
+}
+
+fn size_hint<I: Iterator, const N: usize>(
+    iter: &I,
+    buf: &WindowBuf<I::Item, N>,
+) -> (usize, Option<usize>) {
+    if buf.len < N - 1 {
+        // the source ran out before filling the first window
+        (0, Some(0))
+    } else {
+        iter.size_hint()
+    }
+}
+
+impl<I: Iterator, const N: usize> LendingIterator for ArrayWindows<I, N> {
+    type Item<'a> = &'a [I::Item; N] where Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.buf.len < N - 1 {
+            return None;
+        }
+        let window = self.buf.slide(self.iter.next()?);
+        // SAFETY: the window is initialized, and borrows from `self`.
+        Some(unsafe { &*window })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iter, &self.buf)
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeLendingIterator for ArrayWindows<I, N> {}
+
+impl<I: ExactSizeIterator, const N: usize> HasNextLendingIterator for ArrayWindows<I, N> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator, const N: usize> FusedLendingIterator for ArrayWindows<I, N> {}
+
+/// A lending iterator over mutable windows of a constant size, that doesn't allocate.
+///
+/// This `struct` is created by the [`array_windows_mut`] method on
+/// [`ToLendingIterator`]. See its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`array_windows_mut`]: crate::ToLendingIterator::array_windows_mut
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindowsMut<I: Iterator, const N: usize> {
+    iter: I,
+    buf: WindowBuf<I::Item, N>,
+}
+
+impl<I: Iterator, const N: usize> ArrayWindowsMut<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        let (iter, buf) = fill(iter);
+        Self { iter, buf }
+    }
+}
+
+impl<I: Iterator, const N: usize> LendingIterator for ArrayWindowsMut<I, N> {
+    type Item<'a> = &'a mut [I::Item; N] where Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.buf.len < N - 1 {
+            return None;
+        }
+        let window = self.buf.slide(self.iter.next()?);
+        // SAFETY: the window is initialized, and mutably borrows from `self`.
+        Some(unsafe { &mut *window })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(&self.iter, &self.buf)
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeLendingIterator for ArrayWindowsMut<I, N> {}
+
+impl<I: ExactSizeIterator, const N: usize> HasNextLendingIterator for ArrayWindowsMut<I, N> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator, const N: usize> FusedLendingIterator for ArrayWindowsMut<I, N> {}
+
+#[cfg(test)]
+mod test {
+    use std::rc::Rc;
+
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let v: Vec<usize> = (0..10).collect();
+        let mut windows = v.iter().copied().array_windows::<3>();
+        let mut slice_windows = v.as_slice().windows(3);
+        assert_eq!(windows.len(), slice_windows.len());
+        while let Some(window) = windows.next() {
+            assert_eq!(Some(window.as_slice()), slice_windows.next());
+        }
+        assert_eq!(slice_windows.next(), None);
+        assert_eq!((0..2).array_windows::<3>().size_hint(), (0, Some(0)));
+        assert_eq!((0..2).array_windows::<3>().next(), None);
+        assert_eq!((0..3).array_windows::<1>().count(), 3);
+
+        let mut sums = (0..7).array_windows_mut::<2>();
+        let mut last = 0;
+        while let Some([a, b]) = sums.next() {
+            *b += *a;
+            last = *b;
+        }
+        assert_eq!(last, 21);
+    }
+
+    #[test]
+    fn test_drop() {
+        let rc = Rc::new(());
+        let mut windows = core::iter::repeat(rc.clone()).take(20).array_windows::<4>();
+        windows.nth(6);
+        // the source holds one more
+        assert_eq!(Rc::strong_count(&rc), 1 + 1 + 4);
+        let cloned = windows.clone();
+        drop(windows);
+        assert_eq!(Rc::strong_count(&rc), 1 + 1 + 4);
+        drop(cloned);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let partial = core::iter::repeat(rc.clone())
+            .take(2)
+            .array_windows_mut::<4>();
+        assert_eq!(Rc::strong_count(&rc), 1 + 1 + 2);
+        drop(partial);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// A lending iterator over chunks.
+///
+/// This `struct` is created by the [`chunks`] method on [`ToLendingIterator`]. See
+/// its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`chunks`]: crate::ToLendingIterator::chunks
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<I: Iterator> {
+    iter: I,
+    size: usize,
+    buf: Vec<I::Item>,
+}
+
+impl<I: Iterator> Chunks<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            size,
+            buf: Vec::with_capacity(size),
+        }
+    }
+}
+
+/// Replaces the contents of `buf` with the next `size` elements of `iter`, or as many
+/// as are left.
+pub(super) fn refill<I: Iterator>(iter: &mut I, buf: &mut Vec<I::Item>, size: usize) {
+    buf.clear();
+    buf.extend(iter.by_ref().take(size));
+}
+
+pub(super) fn size_hint((lo, hi): (usize, Option<usize>), size: usize) -> (usize, Option<usize>) {
+    let chunks = |n: usize| n / size + usize::from(n % size != 0);
+    (chunks(lo), hi.map(chunks))
+}
+
+impl<I: Iterator> LendingIterator for Chunks<I> {
+    type Item<'a> = &'a [I::Item]
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        refill(&mut self.iter, &mut self.buf, self.size);
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(&self.buf)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.iter.size_hint(), self.size)
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for Chunks<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for Chunks<I> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for Chunks<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        for size in 1..6 {
+            let v: Vec<usize> = (0..13).collect();
+            let mut chunks = v.iter().copied().chunks(size);
+            let mut expected = v.as_slice().chunks(size);
+            loop {
+                assert_eq!(chunks.len(), expected.len());
+                let chunk = chunks.next();
+                assert_eq!(chunk, expected.next());
+                if chunk.is_none() {
+                    break;
+                }
+            }
+        }
+
+        let mut batches = (0..7).chunks_mut(3);
+        let mut sums = Vec::new();
+        while let Some(batch) = batches.next() {
+            batch.reverse();
+            sums.push(batch[0] * 10 + batch.iter().sum::<i32>());
+        }
+        assert_eq!(sums, [23, 62, 66]);
+    }
+}
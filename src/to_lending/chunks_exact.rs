@@ -0,0 +1,121 @@
+use crate::{
+    to_lending::chunks::refill, ExactSizeLendingIterator, FusedLendingIterator,
+    HasNextLendingIterator, LendingIterator,
+};
+use alloc::vec::Vec;
+
+/// A lending iterator over chunks of exactly the same size.
+///
+/// This `struct` is created by the [`chunks_exact`] method on [`ToLendingIterator`].
+/// See its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`chunks_exact`]: crate::ToLendingIterator::chunks_exact
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksExact<I: Iterator> {
+    iter: I,
+    size: usize,
+    buf: Vec<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator> ChunksExact<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            size,
+            buf: Vec::with_capacity(size),
+            done: false,
+        }
+    }
+
+    /// Returns the elements left over after the last chunk, which are fewer than the
+    /// chunk size.
+    ///
+    /// This is empty until the lending iterator has returned `None`.
+    ///
+    /// See [`ChunksExact::remainder`](core::slice::ChunksExact::remainder).
+    pub fn remainder(&self) -> &[I::Item] {
+        if self.done {
+            &self.buf
+        } else {
+            &[]
+        }
+    }
+
+    /// Consumes the lending iterator, returning the elements left over after the last
+    /// chunk.
+    ///
+    /// See [`remainder`](ChunksExact::remainder).
+    pub fn into_remainder(mut self) -> Vec<I::Item> {
+        if !self.done {
+            self.buf.clear();
+        }
+        self.buf
+    }
+}
+
+impl<I: Iterator> LendingIterator for ChunksExact<I> {
+    type Item<'a> = &'a [I::Item]
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        if self.done {
+            return None;
+        }
+        refill(&mut self.iter, &mut self.buf, self.size);
+        if self.buf.len() < self.size {
+            self.done = true;
+            None
+        } else {
+            Some(&self.buf)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lo, hi) = self.iter.size_hint();
+        (lo / self.size, hi.map(|hi| hi / self.size))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for ChunksExact<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for ChunksExact<I> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: Iterator> FusedLendingIterator for ChunksExact<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let v: Vec<usize> = (0..11).collect();
+        let mut chunks = v.iter().copied().chunks_exact(4);
+        let mut expected = v.as_slice().chunks_exact(4);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.next(), expected.next());
+        assert_eq!(chunks.remainder(), []);
+        assert_eq!(chunks.next(), expected.next());
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), expected.remainder());
+        assert_eq!(chunks.into_remainder(), [8, 9, 10]);
+
+        let mut exact = (0..6).chunks_exact(3);
+        assert_eq!(exact.by_ref().count(), 2);
+        assert_eq!(exact.remainder(), []);
+        assert_eq!((0..6).chunks_exact(4).into_remainder(), []);
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    to_lending::chunks::{refill, size_hint},
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// A lending iterator over mutable chunks.
+///
+/// This `struct` is created by the [`chunks_mut`] method on [`ToLendingIterator`]. See
+/// its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`chunks_mut`]: crate::ToLendingIterator::chunks_mut
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunksMut<I: Iterator> {
+    iter: I,
+    size: usize,
+    buf: Vec<I::Item>,
+}
+
+impl<I: Iterator> ChunksMut<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            size,
+            buf: Vec::with_capacity(size),
+        }
+    }
+}
+
+impl<I: Iterator> LendingIterator for ChunksMut<I> {
+    type Item<'a> = &'a mut [I::Item]
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        refill(&mut self.iter, &mut self.buf, self.size);
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(&mut self.buf)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.iter.size_hint(), self.size)
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for ChunksMut<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for ChunksMut<I> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for ChunksMut<I> {}
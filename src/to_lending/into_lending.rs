@@ -1,4 +1,9 @@
-use crate::LendingIterator;
+use core::iter::{FusedIterator, Peekable};
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator,
+    HasNextLendingIterator, LendingIterator,
+};
 
 /// A lending iterator that iterates over an iterator.
 #[derive(Clone, Debug)]
@@ -24,3 +29,23 @@ impl<I: Iterator> LendingIterator for IntoLending<I> {
         self.iter.size_hint()
     }
 }
+
+impl<I: DoubleEndedIterator> DoubleEndedLendingIterator for IntoLending<I> {
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        self.iter.next_back()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for IntoLending<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for IntoLending<I> {}
+
+impl<I: Iterator> HasNextLendingIterator for IntoLending<Peekable<I>> {
+    fn has_next(&mut self) -> bool {
+        self.iter.peek().is_some()
+    }
+}
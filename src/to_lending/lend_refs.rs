@@ -1,4 +1,9 @@
-use crate::LendingIterator;
+use core::iter::FusedIterator;
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator,
+    HasNextLendingIterator, LendingIterator,
+};
 
 /// A lending iterator that given an iterator, lends
 /// references to the given iterator's items.
@@ -25,6 +30,34 @@ where
         self.item = self.iter.next();
         self.item.as_ref()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedLendingIterator for LendRefs<I>
+where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        self.item = self.iter.next_back();
+        self.item.as_ref()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for LendRefs<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for LendRefs<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for LendRefs<I> {
+    fn has_next(&mut self) -> bool {
+        self.iter.len() != 0
+    }
 }
 
 #[cfg(test)]
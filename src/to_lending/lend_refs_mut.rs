@@ -1,4 +1,9 @@
-use crate::LendingIterator;
+use core::iter::FusedIterator;
+
+use crate::{
+    DoubleEndedLendingIterator, ExactSizeLendingIterator, FusedLendingIterator,
+    HasNextLendingIterator, LendingIterator,
+};
 
 /// A lending iterator that given an iterator, lends
 /// mutable references to the given iterator's items.
@@ -22,6 +27,31 @@ impl<I: Iterator> LendingIterator for LendRefsMut<I> {
         self.item = self.iter.next();
         self.item.as_mut()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedLendingIterator for LendRefsMut<I> {
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        self.item = self.iter.next_back();
+        self.item.as_mut()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for LendRefsMut<I> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for LendRefsMut<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for LendRefsMut<I> {
+    fn has_next(&mut self) -> bool {
+        self.iter.len() != 0
+    }
 }
 
 #[cfg(test)]
@@ -1,3 +1,10 @@
+mod array_windows;
+#[cfg(feature = "alloc")]
+mod chunks;
+#[cfg(feature = "alloc")]
+mod chunks_exact;
+#[cfg(feature = "alloc")]
+mod chunks_mut;
 mod into_lending;
 mod lend_refs;
 mod lend_refs_mut;
@@ -5,8 +12,20 @@ mod lend_refs_mut;
 mod windows;
 #[cfg(feature = "alloc")]
 mod windows_mut;
-pub use self::{into_lending::IntoLending, lend_refs::LendRefs, lend_refs_mut::LendRefsMut};
 #[cfg(feature = "alloc")]
-pub use self::{windows::Windows, windows_mut::WindowsMut};
+mod windows_step;
+#[cfg(feature = "alloc")]
+mod windows_step_mut;
+pub use self::{
+    array_windows::{ArrayWindows, ArrayWindowsMut},
+    into_lending::IntoLending,
+    lend_refs::LendRefs,
+    lend_refs_mut::LendRefsMut,
+};
+#[cfg(feature = "alloc")]
+pub use self::{
+    chunks::Chunks, chunks_exact::ChunksExact, chunks_mut::ChunksMut, windows::Windows,
+    windows_mut::WindowsMut, windows_step::WindowsStep, windows_step_mut::WindowsStepMut,
+};
 
 // TODO: seperate non-buffered and buffered windows
@@ -1,8 +1,15 @@
-use crate::LendingIterator;
+use crate::{
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
 use alloc::vec::Vec;
+use core::iter::FusedIterator;
 
 /// A lending iterator over windows.
 ///
+/// The windows slide through a buffer rather than a ring buffer, so that each of them is
+/// contiguous and can be lent as a slice. See
+/// [`windows_with_capacity`](crate::ToLendingIterator::windows_with_capacity).
+///
 /// This `struct` is created by the [`windows`] method on [`ToLendingIterator`]. See
 /// its documentation for more.
 ///
@@ -13,13 +20,24 @@ use alloc::vec::Vec;
 pub struct Windows<I: Iterator> {
     iter: I,
     size: usize,
+    capacity: usize,
     buf: Vec<I::Item>,
 }
 
 impl<I: Iterator> Windows<I> {
-    pub(crate) fn new(mut iter: I, size: usize) -> Self {
-        let buf = iter.by_ref().take(size - 1).collect();
-        Self { iter, size, buf }
+    pub(crate) fn new(mut iter: I, size: usize, capacity: usize) -> Self {
+        assert!(
+            size <= capacity,
+            "capacity must be at least the window size"
+        );
+        let mut buf = Vec::with_capacity(capacity);
+        buf.extend(iter.by_ref().take(size - 1));
+        Self {
+            iter,
+            size,
+            capacity,
+            buf,
+        }
     }
 }
 
@@ -31,11 +49,137 @@ impl<I: Iterator> LendingIterator for Windows<I> {
 
     fn next(&mut self) -> Option<Self::Item<'_>> {
         self.iter.next().map(|next| {
-            if self.buf.len() == self.size * 2 - 1 {
-                self.buf.drain(..self.size);
+            if self.buf.len() == self.capacity {
+                // keep the last `size - 1` elements, moving them to the front
+                self.buf.drain(..self.capacity - (self.size - 1));
             }
             self.buf.push(next);
             &self.buf[self.buf.len() - self.size..]
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.buf.len() < self.size - 1 {
+            // the source ran out before filling the first window
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for Windows<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for Windows<I> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for Windows<I> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
+    fn first(slice: &[usize]) -> &usize {
+        &slice[0]
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let slice = [0, 1, 2, 3, 4];
+        let mut windows = slice.iter().windows(2);
+        let mut slice_windows = slice.as_slice().windows(2);
+        loop {
+            assert_eq!(windows.size_hint(), slice_windows.size_hint());
+            if windows.next().is_none() {
+                break;
+            }
+            slice_windows.next();
+        }
+        assert_eq!((0..2).windows(3).size_hint(), (0, Some(0)));
+        assert_eq!((0..5).windows(3).map(first).last(), Some(&2));
+        assert_eq!(
+            (0..5)
+                .windows(3)
+                .map(first)
+                .cloned()
+                .into_iter()
+                .size_hint(),
+            (3, Some(3))
+        );
+    }
+
+    #[test]
+    fn test_len() {
+        let v: Vec<usize> = (0..10).collect();
+        assert_eq!(v.iter().windows(3).len(), v.as_slice().windows(3).len());
+        assert_eq!(v.iter().windows(11).len(), 0);
+        assert_eq!(v.iter().windows_mut(10).len(), 1);
+
+        let mut windows = v.iter().windows(3).enumerate().skip(1).step_by(2).take(3);
+        let mut slice_windows = v
+            .as_slice()
+            .windows(3)
+            .enumerate()
+            .skip(1)
+            .step_by(2)
+            .take(3);
+        assert_eq!(windows.len(), slice_windows.len());
+        windows.next();
+        slice_windows.next();
+        assert_eq!(windows.len(), slice_windows.len());
+
+        let zipped = v.iter().windows(4).zip(v.iter().lend_refs()).fuse();
+        assert_eq!(zipped.len(), 7);
+        let reversed = v.iter().into_lending().zip(v.iter().lend_refs_mut()).rev();
+        assert_eq!(reversed.len(), 10);
+        let statics: &'static [usize] = &[0, 1, 2];
+        assert_eq!(statics.iter().into_lending().cloned().len(), 3);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let v: Vec<usize> = (0..1000).collect();
+        for size in [1, 2, 7, 32] {
+            for capacity in [size, size + 1, size * 2 - 1, size * 8] {
+                let mut windows = v.iter().copied().windows_with_capacity(size, capacity);
+                let mut slice_windows = v.as_slice().windows(size);
+                // every element stays in the windows until it is lent for the last time,
+                // so each move shows up as a changed address in the following window
+                let mut addresses = vec![core::ptr::null::<usize>(); v.len()];
+                let mut moves = 0;
+                while let Some(window) = windows.next() {
+                    assert_eq!(Some(window), slice_windows.next());
+                    for x in window {
+                        let address: *const usize = x;
+                        if !addresses[*x].is_null() && addresses[*x] != address {
+                            moves += 1;
+                        }
+                        addresses[*x] = address;
+                    }
+                }
+                assert_eq!(slice_windows.next(), None);
+                // every element is moved (size - 1) / (capacity - size + 1) times on average
+                assert!(moves <= v.len() * (size - 1) / (capacity - size + 1));
+                if capacity == size {
+                    assert_eq!(moves, (v.len() - size) * (size - 1));
+                }
+            }
+        }
+
+        let mut windows = (0..5).windows_mut_with_capacity(2, 2);
+        let mut sums = Vec::new();
+        while let Some([a, b]) = windows.next() {
+            *b += *a;
+            sums.push(*b);
+        }
+        assert_eq!(sums, [1, 3, 6, 10]);
+    }
+
+    #[test]
+    #[should_panic = "capacity must be at least the window size"]
+    fn test_capacity_too_small() {
+        let _ = (0..5).windows_with_capacity(3, 2);
+    }
 }
@@ -1,5 +1,8 @@
-use crate::LendingIterator;
+use crate::{
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
 use alloc::vec::Vec;
+use core::iter::FusedIterator;
 
 /// A lending iterator over mutable windows.
 ///
@@ -13,13 +16,24 @@ use alloc::vec::Vec;
 pub struct WindowsMut<I: Iterator> {
     iter: I,
     size: usize,
+    capacity: usize,
     buf: Vec<I::Item>,
 }
 
 impl<I: Iterator> WindowsMut<I> {
-    pub(crate) fn new(mut iter: I, size: usize) -> Self {
-        let buf = iter.by_ref().take(size - 1).collect();
-        Self { iter, size, buf }
+    pub(crate) fn new(mut iter: I, size: usize, capacity: usize) -> Self {
+        assert!(
+            size <= capacity,
+            "capacity must be at least the window size"
+        );
+        let mut buf = Vec::with_capacity(capacity);
+        buf.extend(iter.by_ref().take(size - 1));
+        Self {
+            iter,
+            size,
+            capacity,
+            buf,
+        }
     }
 }
 
@@ -31,12 +45,32 @@ impl<I: Iterator> LendingIterator for WindowsMut<I> {
 
     fn next(&mut self) -> Option<Self::Item<'_>> {
         self.iter.next().map(|next| {
-            if self.buf.len() == self.size * 2 - 1 {
-                self.buf.drain(..self.size);
+            if self.buf.len() == self.capacity {
+                // keep the last `size - 1` elements, moving them to the front
+                self.buf.drain(..self.capacity - (self.size - 1));
             }
             self.buf.push(next);
             let range = self.buf.len() - self.size..;
             &mut self.buf[range]
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.buf.len() < self.size - 1 {
+            // the source ran out before filling the first window
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
 }
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for WindowsMut<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for WindowsMut<I> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for WindowsMut<I> {}
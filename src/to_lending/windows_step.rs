@@ -0,0 +1,172 @@
+use crate::{
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// A lending iterator over windows that start `step` elements apart.
+///
+/// This `struct` is created by the [`windows_step`] method on [`ToLendingIterator`].
+/// See its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`windows_step`]: crate::ToLendingIterator::windows_step
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WindowsStep<I: Iterator> {
+    iter: I,
+    size: usize,
+    step: usize,
+    buf: Vec<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator> WindowsStep<I> {
+    pub(crate) fn new(iter: I, size: usize, step: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        assert!(step != 0, "step must be non-zero");
+        Self {
+            iter,
+            size,
+            step,
+            buf: Vec::new(),
+            started: false,
+        }
+    }
+}
+
+/// Reads the next window into the end of `buf`, returning `None` if `iter` ran out.
+pub(super) fn advance<I: Iterator>(
+    iter: &mut I,
+    buf: &mut Vec<I::Item>,
+    size: usize,
+    step: usize,
+    started: &mut bool,
+) -> Option<()> {
+    let needed = if !*started {
+        size
+    } else if step < size {
+        // keep the overlap, but only shift it once the buffer reaches size * 2
+        if buf.len() + step > size * 2 {
+            buf.drain(..buf.len() - (size - step));
+        }
+        step
+    } else {
+        buf.clear();
+        if step > size {
+            iter.nth(step - size - 1)?;
+        }
+        size
+    };
+    for _ in 0..needed {
+        buf.push(iter.next()?);
+    }
+    *started = true;
+    Some(())
+}
+
+pub(super) fn size_hint(
+    (lo, hi): (usize, Option<usize>),
+    size: usize,
+    step: usize,
+    started: bool,
+) -> (usize, Option<usize>) {
+    let windows = |n: usize| {
+        if started {
+            n / step
+        } else if n < size {
+            0
+        } else {
+            (n - size) / step + 1
+        }
+    };
+    (windows(lo), hi.map(windows))
+}
+
+impl<I: Iterator> LendingIterator for WindowsStep<I> {
+    type Item<'a> = &'a [I::Item]
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        advance(
+            &mut self.iter,
+            &mut self.buf,
+            self.size,
+            self.step,
+            &mut self.started,
+        )?;
+        Some(&self.buf[self.buf.len() - self.size..])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.iter.size_hint(), self.size, self.step, self.started)
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for WindowsStep<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for WindowsStep<I> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for WindowsStep<I> {}
+
+#[cfg(test)]
+mod test {
+    use core::{cell::Cell, ops::Range};
+
+    use crate::{ExactSizeLendingIterator, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        for size in 1..5 {
+            for step in 1..7 {
+                let v: Vec<usize> = (0..20).collect();
+                let mut windows = v.iter().copied().windows_step(size, step);
+                let mut expected = v.as_slice().windows(size).step_by(step);
+                loop {
+                    assert_eq!(windows.len(), expected.len());
+                    let window = windows.next();
+                    assert_eq!(window, expected.next());
+                    if window.is_none() {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_skips() {
+        // counts the elements read with `next`, but not the ones skipped with `nth`
+        struct Counted<'a>(Range<usize>, &'a Cell<usize>);
+
+        impl Iterator for Counted<'_> {
+            type Item = usize;
+
+            fn next(&mut self) -> Option<usize> {
+                self.1.set(self.1.get() + 1);
+                self.0.next()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<usize> {
+                self.0.nth(n)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.0.size_hint()
+            }
+        }
+
+        let reads = Cell::new(0);
+        let mut windows = Counted(0..100, &reads).windows_step_mut(3, 10);
+        assert_eq!(windows.nth(2), Some([20, 21, 22].as_mut_slice()));
+        assert_eq!(reads.get(), 9);
+        assert_eq!(windows.size_hint(), (7, Some(7)));
+        assert_eq!(windows.count(), 7);
+    }
+}
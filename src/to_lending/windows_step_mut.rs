@@ -0,0 +1,70 @@
+use crate::{
+    to_lending::windows_step::{advance, size_hint},
+    ExactSizeLendingIterator, FusedLendingIterator, HasNextLendingIterator, LendingIterator,
+};
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+/// A lending iterator over mutable windows that start `step` elements apart.
+///
+/// This `struct` is created by the [`windows_step_mut`] method on
+/// [`ToLendingIterator`]. See its documentation for more.
+///
+/// [`ToLendingIterator`]: crate::ToLendingIterator
+/// [`windows_step_mut`]: crate::ToLendingIterator::windows_step_mut
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WindowsStepMut<I: Iterator> {
+    iter: I,
+    size: usize,
+    step: usize,
+    buf: Vec<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator> WindowsStepMut<I> {
+    pub(crate) fn new(iter: I, size: usize, step: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        assert!(step != 0, "step must be non-zero");
+        Self {
+            iter,
+            size,
+            step,
+            buf: Vec::new(),
+            started: false,
+        }
+    }
+}
+
+impl<I: Iterator> LendingIterator for WindowsStepMut<I> {
+    type Item<'a> = &'a mut [I::Item]
+        where
+            Self: 'a
+    ;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        advance(
+            &mut self.iter,
+            &mut self.buf,
+            self.size,
+            self.step,
+            &mut self.started,
+        )?;
+        let range = self.buf.len() - self.size..;
+        Some(&mut self.buf[range])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(self.iter.size_hint(), self.size, self.step, self.started)
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeLendingIterator for WindowsStepMut<I> {}
+
+impl<I: ExactSizeIterator> HasNextLendingIterator for WindowsStepMut<I> {
+    fn has_next(&mut self) -> bool {
+        self.len() != 0
+    }
+}
+
+impl<I: FusedIterator> FusedLendingIterator for WindowsStepMut<I> {}
@@ -0,0 +1,179 @@
+use core::{borrow::Borrow, convert::Infallible, ops::ControlFlow};
+
+use stable_try_trait_v2::Try;
+
+use crate::LendingIterator;
+
+/// Trait to represent types that can be created by summing up a lending iterator.
+///
+/// This trait is used to implement [`LendingIterator::sum`]. Unlike [`Sum`], it is
+/// generic over the whole lending iterator, since its items may borrow from it.
+/// Primitive numbers can be summed from any items that [`Borrow`] them, so both
+/// owned and borrowed items work, and [`Option`]s and [`Result`]s of them can be
+/// summed from items whose [`Try`] output does.
+///
+/// See [`Sum`].
+///
+/// [`Sum`]: core::iter::Sum
+pub trait SumLending<I: LendingIterator>: Sized {
+    /// Takes a lending iterator and generates `Self` from the elements by "summing up"
+    /// the items.
+    fn sum_lending(iter: I) -> Self;
+}
+
+/// Trait to represent types that can be created by multiplying elements of a
+/// lending iterator.
+///
+/// This trait is used to implement [`LendingIterator::product`]. Unlike [`Product`],
+/// it is generic over the whole lending iterator, since its items may borrow from it.
+/// Primitive numbers can be multiplied from any items that [`Borrow`] them, so both
+/// owned and borrowed items work, and [`Option`]s and [`Result`]s of them can be
+/// multiplied from items whose [`Try`] output does.
+///
+/// See [`Product`].
+///
+/// [`Product`]: core::iter::Product
+pub trait ProductLending<I: LendingIterator>: Sized {
+    /// Takes a lending iterator and generates `Self` from the elements by multiplying
+    /// the items.
+    fn product_lending(iter: I) -> Self;
+}
+
+macro_rules! sum_product {
+    ($zero:expr, $one:expr, $($a:ty)*) => ($(
+        impl<I> SumLending<I> for $a
+        where
+            I: LendingIterator,
+            for<'a> I::Item<'a>: Borrow<$a>,
+        {
+            #[inline]
+            fn sum_lending(iter: I) -> Self {
+                iter.fold($zero, |acc, x| acc + *x.borrow())
+            }
+        }
+
+        impl<I> ProductLending<I> for $a
+        where
+            I: LendingIterator,
+            for<'a> I::Item<'a>: Borrow<$a>,
+        {
+            #[inline]
+            fn product_lending(iter: I) -> Self {
+                iter.fold($one, |acc, x| acc * *x.borrow())
+            }
+        }
+
+        impl<I> SumLending<I> for Option<$a>
+        where
+            I: LendingIterator,
+            for<'a> I::Item<'a>: Try<Residual = Option<Infallible>>,
+            for<'a> <I::Item<'a> as Try>::Output: Borrow<$a>,
+        {
+            #[inline]
+            fn sum_lending(mut iter: I) -> Self {
+                try_accumulate(&mut iter, $zero, |a, b| a + b).ok()
+            }
+        }
+
+        impl<I> ProductLending<I> for Option<$a>
+        where
+            I: LendingIterator,
+            for<'a> I::Item<'a>: Try<Residual = Option<Infallible>>,
+            for<'a> <I::Item<'a> as Try>::Output: Borrow<$a>,
+        {
+            #[inline]
+            fn product_lending(mut iter: I) -> Self {
+                try_accumulate(&mut iter, $one, |a, b| a * b).ok()
+            }
+        }
+
+        impl<I, E> SumLending<I> for Result<$a, E>
+        where
+            I: LendingIterator,
+            for<'a> I::Item<'a>: Try<Residual = Result<Infallible, E>>,
+            for<'a> <I::Item<'a> as Try>::Output: Borrow<$a>,
+        {
+            #[inline]
+            fn sum_lending(mut iter: I) -> Self {
+                try_accumulate(&mut iter, $zero, |a, b| a + b).map_err(|Err(e)| e)
+            }
+        }
+
+        impl<I, E> ProductLending<I> for Result<$a, E>
+        where
+            I: LendingIterator,
+            for<'a> I::Item<'a>: Try<Residual = Result<Infallible, E>>,
+            for<'a> <I::Item<'a> as Try>::Output: Borrow<$a>,
+        {
+            #[inline]
+            fn product_lending(mut iter: I) -> Self {
+                try_accumulate(&mut iter, $one, |a, b| a * b).map_err(|Err(e)| e)
+            }
+        }
+    )*);
+}
+
+sum_product! { 0, 1, i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+sum_product! { -0.0, 1.0, f32 f64 }
+
+/// Folds the outputs of the [`Try`] items of `iter`, stopping at the first residual.
+fn try_accumulate<I, A, R>(iter: &mut I, init: A, f: fn(A, A) -> A) -> Result<A, R>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: Try<Residual = R>,
+    for<'a> <I::Item<'a> as Try>::Output: Borrow<A>,
+    A: Copy,
+{
+    match iter.try_fold(init, |acc, x| match x.branch() {
+        ControlFlow::Continue(x) => ControlFlow::Continue(f(acc, *x.borrow())),
+        ControlFlow::Break(r) => ControlFlow::Break(r),
+    }) {
+        ControlFlow::Continue(acc) => Ok(acc),
+        ControlFlow::Break(r) => Err(r),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        assert_eq!((1..5).into_lending().sum::<u64>(), 10);
+        assert_eq!((1..5).into_lending().product::<u64>(), 24);
+        assert!((vec![1.5, 2.5].lend_refs().sum::<f64>() - 4.0).abs() < f64::EPSILON);
+        assert_eq!(
+            (0..6)
+                .windows(3)
+                .map(|w: &[u64]| w.iter().sum::<u64>())
+                .sum::<u64>(),
+            (0..6)
+                .collect::<Vec<u64>>()
+                .as_slice()
+                .windows(3)
+                .flatten()
+                .sum()
+        );
+    }
+
+    #[test]
+    fn test_try() {
+        let xs = [Some(1), Some(2), Some(3)];
+        assert_eq!(xs.into_lending().sum::<Option<i32>>(), Some(6));
+        assert_eq!(xs.into_lending().product::<Option<i32>>(), Some(6));
+        let xs = [Some(1), None, Some(3)];
+        assert_eq!(xs.into_lending().sum::<Option<i32>>(), None);
+
+        let rs: [Result<u8, &str>; 3] = [Ok(1), Err("a"), Err("b")];
+        assert_eq!(rs.into_lending().sum::<Result<u8, _>>(), Err("a"));
+        let rs: [Result<u8, &str>; 2] = [Ok(2), Ok(3)];
+        assert_eq!(rs.into_lending().product::<Result<u8, _>>(), Ok(6));
+        assert_eq!(
+            vec![4, 5, 6]
+                .lend_refs()
+                .map(|x: &u32| x.checked_sub(4))
+                .sum::<Option<u32>>(),
+            Some(3)
+        );
+    }
+}
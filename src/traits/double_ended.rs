@@ -0,0 +1,130 @@
+use core::{num::NonZeroUsize, ops::ControlFlow};
+
+use stable_try_trait_v2::{try_, Try};
+
+use crate::{ExactSizeLendingIterator, LendingIterator};
+
+/// A lending iterator able to yield elements from both ends.
+///
+/// see [`DoubleEndedIterator`](::core::iter::DoubleEndedIterator).
+pub trait DoubleEndedLendingIterator: LendingIterator {
+    /// Removes and returns an element from the end of the lending iterator.
+    ///
+    /// See [`DoubleEndedIterator::next_back`].
+    fn next_back(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Advances the lending iterator from the back by `n` elements.
+    ///
+    /// See [`DoubleEndedIterator::advance_back_by`].
+    #[inline]
+    #[allow(clippy::missing_errors_doc)]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        for i in 0..n {
+            if self.next_back().is_none() {
+                // SAFETY: `i` is always less than `n`.
+                return Err(unsafe { NonZeroUsize::new_unchecked(n - i) });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the `n`th element from the end of the lending iterator.
+    ///
+    /// See [`DoubleEndedIterator::nth_back`].
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        self.advance_back_by(n).ok()?;
+        self.next_back()
+    }
+
+    /// The reverse version of [`LendingIterator::try_fold`]: it takes
+    /// elements starting from the back of the lending iterator.
+    ///
+    /// See [`DoubleEndedIterator::try_rfold`].
+    #[inline]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, Self::Item<'all>) -> R,
+        R: Try<Output = B>,
+    {
+        let mut acc = init;
+        while let Some(x) = self.next_back() {
+            acc = try_!(f(acc, x));
+        }
+        Try::from_output(acc)
+    }
+
+    /// A lending iterator method that reduces the lending iterator's elements to a single,
+    /// final value, starting from the back.
+    ///
+    /// See [`DoubleEndedIterator::rfold`].
+    #[inline]
+    fn rfold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        Self: Sized,
+        for<'all> F: FnMut(B, Self::Item<'all>) -> B,
+    {
+        let mut acc = init;
+        while let Some(x) = self.next_back() {
+            acc = f(acc, x);
+        }
+        acc
+    }
+
+    /// Searches for an element of a lending iterator from the back that satisfies a predicate.
+    ///
+    /// See [`DoubleEndedIterator::rfind`].
+    #[inline]
+    fn rfind<'a, P>(&'a mut self, mut predicate: P) -> Option<Self::Item<'a>>
+    where
+        Self: Sized,
+        for<'all> P: FnMut(&Self::Item<'all>) -> bool,
+    {
+        while let Some(x) = self.next_back() {
+            if predicate(&x) {
+                // SAFETY: `x` is the last value yielded by `self`. polonious return
+                return Some(unsafe { core::mem::transmute::<Self::Item<'_>, Self::Item<'a>>(x) });
+            }
+        }
+        None
+    }
+
+    /// Searches for an element in a lending iterator from the right, returning its index.
+    ///
+    /// See [`Iterator::rposition`].
+    #[inline]
+    fn rposition<P>(&mut self, mut predicate: P) -> Option<usize>
+    where
+        Self: Sized + ExactSizeLendingIterator,
+        for<'all> P: FnMut(Self::Item<'all>) -> bool,
+    {
+        let n = self.len();
+        match self.try_rfold(n, move |i, x| {
+            let i = i - 1;
+            if predicate(x) {
+                ControlFlow::Break(i)
+            } else {
+                ControlFlow::Continue(i)
+            }
+        }) {
+            ControlFlow::Break(i) => Some(i),
+            ControlFlow::Continue(_) => None,
+        }
+    }
+}
+
+impl<I: DoubleEndedLendingIterator + ?Sized> DoubleEndedLendingIterator for &mut I {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item<'_>> {
+        (**self).next_back()
+    }
+    #[inline]
+    fn advance_back_by(&mut self, n: usize) -> Result<(), NonZeroUsize> {
+        (**self).advance_back_by(n)
+    }
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item<'_>> {
+        (**self).nth_back(n)
+    }
+}
@@ -0,0 +1,127 @@
+use core::marker::PhantomData;
+
+use crate::LendingIterator;
+
+/// A family of item types, one for each lifetime.
+///
+/// [`LendingIterator::Item`] can't be named by a trait object, so [`DynLendingIterator`]
+/// names its items through a `ForLt` instead. [`Ref`], [`RefMut`] and [`Owned`] cover
+/// the common cases, and it can be implemented for any other item type:
+///
+/// ```
+/// # use gat_lending_iterator::ForLt;
+/// struct Pair;
+///
+/// impl ForLt for Pair {
+///     type Of<'a> = (usize, &'a [u8]);
+/// }
+/// ```
+pub trait ForLt {
+    /// The item type for the lifetime `'a`.
+    type Of<'a>;
+}
+
+/// The [`ForLt`] of `&'a T`.
+pub struct Ref<T: ?Sized + 'static>(PhantomData<T>);
+
+impl<T: ?Sized + 'static> ForLt for Ref<T> {
+    type Of<'a> = &'a T;
+}
+
+/// The [`ForLt`] of `&'a mut T`.
+pub struct RefMut<T: ?Sized + 'static>(PhantomData<T>);
+
+impl<T: ?Sized + 'static> ForLt for RefMut<T> {
+    type Of<'a> = &'a mut T;
+}
+
+/// The [`ForLt`] of `T`, for items that don't borrow from the lending iterator.
+pub struct Owned<T>(PhantomData<T>);
+
+impl<T> ForLt for Owned<T> {
+    type Of<'a> = T;
+}
+
+/// An object safe version of [`LendingIterator`], whose items are named by the
+/// [`ForLt`] `L`.
+///
+/// It is implemented for every lending iterator whose `Item<'a>` is `L::Of<'a>`, and
+/// `dyn DynLendingIterator<L>` is itself a [`LendingIterator`], so lending iterators of
+/// different types can be used through the same `&mut dyn DynLendingIterator<L>`, or
+/// stored with [`boxed`](LendingIterator::boxed).
+///
+/// Proving that the items match for every `'a` currently requires the lending iterator
+/// to be `'static`.
+///
+/// ```
+/// # use gat_lending_iterator::{DynLendingIterator, Ref, ToLendingIterator};
+/// let mut windows = (0..4).windows(2);
+/// let mut others = vec![7, 8, 9].into_iter().windows(3);
+/// let iters: [&mut dyn DynLendingIterator<Ref<[i32]>>; 2] = [&mut windows, &mut others];
+/// let mut sums = Vec::new();
+/// for iter in iters {
+///     while let Some(w) = iter.next() {
+///         sums.push(w.iter().sum::<i32>());
+///     }
+/// }
+/// assert_eq!(sums, [1, 3, 5, 24]);
+/// ```
+pub trait DynLendingIterator<L: ForLt + ?Sized> {
+    /// Advances the lending iterator and returns the next value.
+    ///
+    /// See [`LendingIterator::next`].
+    fn next(&mut self) -> Option<L::Of<'_>>;
+
+    /// Returns the bounds on the remaining length of the lending iterator.
+    ///
+    /// See [`LendingIterator::size_hint`].
+    fn size_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<I, L> DynLendingIterator<L> for I
+where
+    I: LendingIterator,
+    L: ForLt + ?Sized,
+    for<'a> I: ItemAt<'a, Item = L::Of<'a>>,
+{
+    #[inline]
+    fn next(&mut self) -> Option<L::Of<'_>> {
+        item_at::<I>(LendingIterator::next(self))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        LendingIterator::size_hint(self)
+    }
+}
+
+/// The item of a lending iterator for the lifetime `'a`, named without requiring
+/// `I: 'a` up front.
+pub trait ItemAt<'a> {
+    /// The item type.
+    type Item;
+}
+
+impl<'a, I: LendingIterator + 'a> ItemAt<'a> for I {
+    type Item = I::Item<'a>;
+}
+
+fn item_at<'a, I: LendingIterator + 'a>(
+    item: Option<I::Item<'a>>,
+) -> Option<<I as ItemAt<'a>>::Item> {
+    item
+}
+
+impl<L: ForLt + ?Sized> LendingIterator for dyn DynLendingIterator<L> + '_ {
+    type Item<'a> = L::Of<'a> where Self: 'a;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        DynLendingIterator::next(self)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        DynLendingIterator::size_hint(self)
+    }
+}
@@ -3,6 +3,7 @@ use crate::LendingIterator;
 /// A lending iterator that knows its exact length.
 ///
 /// see [`ExactSizeIterator`](::core::iter::ExactSizeIterator).
+#[allow(clippy::len_without_is_empty)]
 pub trait ExactSizeLendingIterator: LendingIterator {
     /// Returns the exact remaining length of the iterator.
     ///
@@ -0,0 +1,228 @@
+use alloc::borrow::ToOwned;
+
+use crate::LendingIterator;
+
+/// Conversion of a lending iterator's item into a value that doesn't borrow from it.
+///
+/// References are converted with [`ToOwned`], so `&[T]` becomes `Vec<T>` and `&str`
+/// becomes `String`. Primitives are returned as is, and tuples convert each of their
+/// fields.
+pub trait ToOwnedItem {
+    /// The resulting type after conversion.
+    type Owned;
+
+    /// Converts the item into its owned counterpart.
+    fn to_owned_item(self) -> Self::Owned;
+}
+
+impl<T: ToOwned + ?Sized> ToOwnedItem for &T {
+    type Owned = T::Owned;
+
+    #[inline]
+    fn to_owned_item(self) -> Self::Owned {
+        self.to_owned()
+    }
+}
+
+impl<T: ToOwned + ?Sized> ToOwnedItem for &mut T {
+    type Owned = T::Owned;
+
+    #[inline]
+    fn to_owned_item(self) -> Self::Owned {
+        (*self).to_owned()
+    }
+}
+
+macro_rules! primitive_to_owned_item {
+    ($($t:ty)*) => ($(
+        impl ToOwnedItem for $t {
+            type Owned = $t;
+
+            #[inline]
+            fn to_owned_item(self) -> Self::Owned {
+                self
+            }
+        }
+    )*);
+}
+
+primitive_to_owned_item! { () bool char f32 f64 i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+macro_rules! tuple_to_owned_item {
+    ($($t:ident)+) => {
+        impl<$($t: ToOwnedItem),+> ToOwnedItem for ($($t,)+) {
+            type Owned = ($($t::Owned,)+);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn to_owned_item(self) -> Self::Owned {
+                let ($($t,)+) = self;
+                ($($t.to_owned_item(),)+)
+            }
+        }
+    };
+}
+
+tuple_to_owned_item! { A }
+tuple_to_owned_item! { A B }
+tuple_to_owned_item! { A B C }
+tuple_to_owned_item! { A B C D }
+
+/// Conversion from a [`LendingIterator`].
+///
+/// This trait is used to implement [`LendingIterator::collect_owned`]. It is
+/// implemented for every [`FromIterator`] collection, which is built from the items
+/// converted with [`ToOwnedItem`].
+///
+/// See [`FromIterator`].
+pub trait FromLendingIterator<I: LendingIterator>: Sized {
+    /// Creates a value from a lending iterator.
+    fn from_lending_iter(iter: I) -> Self;
+}
+
+impl<I, A, C> FromLendingIterator<I> for C
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: ToOwnedItem<Owned = A>,
+    C: FromIterator<A>,
+{
+    #[inline]
+    fn from_lending_iter(iter: I) -> Self {
+        OwnedItems { iter }.collect()
+    }
+}
+
+/// Extend a collection with the contents of a [`LendingIterator`].
+///
+/// It is implemented for every [`Extend`] collection, which is extended with the items
+/// converted with [`ToOwnedItem`], without collecting them anywhere else first.
+///
+/// See [`Extend`].
+pub trait ExtendLending<I: LendingIterator> {
+    /// Extends a collection with the contents of a lending iterator.
+    fn extend_lending(&mut self, iter: I);
+}
+
+impl<I, A, C> ExtendLending<I> for C
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: ToOwnedItem<Owned = A>,
+    C: Extend<A>,
+{
+    #[inline]
+    fn extend_lending(&mut self, iter: I) {
+        self.extend(OwnedItems { iter });
+    }
+}
+
+/// An iterator over the owned copies of the items of a lending iterator.
+struct OwnedItems<I> {
+    iter: I,
+}
+
+impl<I, A> Iterator for OwnedItems<I>
+where
+    I: LendingIterator,
+    for<'a> I::Item<'a>: ToOwnedItem<Owned = A>,
+{
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(ToOwnedItem::to_owned_item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+    use crate::{ExtendLending, LendingIterator, ToLendingIterator};
+
+    #[test]
+    fn test() {
+        let windows: Vec<Vec<usize>> = (0..4).windows(2).collect_owned();
+        assert_eq!(windows, vec![vec![0, 1], vec![1, 2], vec![2, 3]]);
+
+        let deque = (0..3).windows(2).collect_owned::<VecDeque<_>>();
+        assert_eq!(deque.back(), Some(&vec![1, 2]));
+
+        let set = [3, 1, 3, 2].lend_refs().collect_owned::<BTreeSet<_>>();
+        assert_eq!(set.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let string = ["a", "b"].into_lending().collect_owned::<String>();
+        assert_eq!(string, "ab");
+
+        let windows = (0..4).windows_mut(2).collect_owned::<Vec<Vec<_>>>();
+        assert_eq!(windows, vec![vec![0, 1], vec![1, 2], vec![2, 3]]);
+
+        let map = (0..4)
+            .windows(2)
+            .enumerate()
+            .collect_owned::<HashMap<_, _>>();
+        assert_eq!(map[&2], vec![2, 3]);
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut buf = vec![vec![9, 9]];
+        for n in 3..5 {
+            buf.extend_lending((0..n).windows(n - 1));
+        }
+        assert_eq!(
+            buf,
+            vec![
+                vec![9, 9],
+                vec![0, 1],
+                vec![1, 2],
+                vec![0, 1, 2],
+                vec![1, 2, 3]
+            ]
+        );
+
+        let mut string = String::from("a");
+        string.extend_lending(["b", "c"].into_lending());
+        assert_eq!(string, "abc");
+
+        let mut deque = VecDeque::from([0]);
+        deque.extend_lending([1, 2].lend_refs());
+        assert_eq!(deque, [0, 1, 2]);
+
+        let mut set = HashSet::from([vec![0, 1]]);
+        set.extend_lending((0..3).windows(2));
+        assert_eq!(set.len(), 2);
+
+        let mut map = HashMap::new();
+        map.extend_lending((0..3).windows(2).enumerate());
+        assert_eq!(map[&1], vec![1, 2]);
+
+        let mut map = BTreeMap::from([(0, 'x')]);
+        map.extend_lending("ab".chars().into_lending().enumerate().skip(1));
+        assert_eq!(map.into_values().collect::<String>(), "xb");
+    }
+
+    #[test]
+    fn test_partition_unzip() {
+        let (rising, falling): (Vec<Vec<_>>, Vec<Vec<_>>) = [1, 3, 2, 4]
+            .windows(2)
+            .partition_owned(|w: &&[i32]| w[0] < w[1]);
+        assert_eq!(rising, vec![vec![1, 3], vec![2, 4]]);
+        assert_eq!(falling, vec![vec![3, 2]]);
+
+        let (indices, windows): (Vec<_>, VecDeque<Vec<_>>) =
+            (0..3).windows(2).enumerate().unzip_owned();
+        assert_eq!(indices, vec![0, 1]);
+        assert_eq!(windows, [vec![0, 1], vec![1, 2]]);
+
+        let (xs, ys): (Vec<_>, String) = (0..2)
+            .into_lending()
+            .zip(["a", "b"].into_lending())
+            .unzip_owned();
+        assert_eq!((xs, ys), (vec![0, 1], String::from("ab")));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::LendingIterator;
+
+/// A lending iterator that always continues to yield `None` when exhausted.
+///
+/// Calling next on a fused lending iterator that has returned `None` once is guaranteed
+/// to return [`None`] again. This trait should be implemented by all lending iterators
+/// that behave this way because it allows optimizing [`LendingIterator::fuse()`].
+///
+/// see [`FusedIterator`](::core::iter::FusedIterator).
+pub trait FusedLendingIterator: LendingIterator {}
+
+impl<I: FusedLendingIterator + ?Sized> FusedLendingIterator for &mut I {}
@@ -1,11 +1,20 @@
-use ::core::{num::NonZeroUsize, ops::Deref};
+#[cfg(feature = "alloc")]
+use ::alloc::borrow::ToOwned;
+use ::core::{borrow::Borrow, cmp::Ordering, num::NonZeroUsize, ops::Deref};
 use core::{mem::transmute, ops::ControlFlow};
 
 use stable_try_trait_v2::{try_, ChangeOutputType, FromResidual, Residual, Try};
 
+#[cfg(feature = "alloc")]
 use crate::{
-    Chain, Cloned, Enumerate, ExactSizeLendingIterator, Filter, FilterMap, Map, OptionTrait,
-    Peekable, SingleArgFnMut, SingleArgFnOnce, Skip, StepBy, Take, TakeWhile, Zip,
+    BoxedLendingIterator, DynLendingIterator, ExtendLending, ForLt, FromLendingIterator, MultiPeek,
+    ToOwnedItem,
+};
+use crate::{
+    Chain, Cloned, DoubleEndedLendingIterator, Enumerate, Filter, FilterMap, Fuse,
+    HasNextLendingIterator, Inspect, Intersperse, IntersperseWith, IntoOwnedIter, Map, MapWhile,
+    OptionTrait, Peekable, PeekableOwned, ProductLending, PutBack, Rev, Scan, SingleArgFnMut,
+    SingleArgFnOnce, Skip, SkipWhile, StepBy, SumLending, Take, TakeWhile, Zip,
 };
 
 /// Like [`Iterator`], but items may borrow from `&mut self`.
@@ -120,9 +129,93 @@ pub trait LendingIterator {
         TakeWhile::new(self, predicate)
     }
 
+    /// Creates a lending iterator that both yields elements based on a predicate and maps.
+    ///
+    /// `map_while()` takes a closure as an argument. It will call this
+    /// closure on each element of the lending iterator, and yield elements
+    /// while it returns [`Some(_)`][`Some`].
+    ///
+    /// In the case that the closure's return type doesn't borrow from its input,
+    /// the resulting `LendingIterator` will implement [`IntoIterator`].
+    ///
+    /// See [`Iterator::map_while`].
+    #[inline]
+    fn map_while<F>(self, f: F) -> MapWhile<Self, F>
+    where
+        Self: Sized,
+        for<'all> F: SingleArgFnMut<Self::Item<'all>>,
+        for<'all> <F as SingleArgFnOnce<Self::Item<'all>>>::Output: OptionTrait,
+    {
+        MapWhile::new(self, f)
+    }
+
     /// Takes two lending iterators and creates a new lending iterator over both in sequence.
     ///
     /// See [`Iterator::chain`].
+    ///
+    /// # Limitations
+    ///
+    /// Both lending iterators must lend the same items for *every* lifetime. Because of the
+    /// `Self: 'a` bound on [`Item`](Self::Item), the compiler can currently only prove that
+    /// when both of them are `'static`, so two lending iterators borrowing from a local
+    /// can't be chained directly, even though each of them can be used on its own:
+    ///
+    /// ```compile_fail
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let (a, b) = (vec![1, 2], vec![3]);
+    /// let mut chain = a.iter().into_lending().chain(b.iter().into_lending());
+    /// while let Some(x) = chain.next() {
+    ///     println!("{x}");
+    /// }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let (a, b) = (vec![1, 2], vec![3, 4]);
+    /// let mut chain = a.iter().array_windows::<2>().chain(b.iter().array_windows::<2>());
+    /// while let Some(x) = chain.next() {
+    ///     println!("{x:?}");
+    /// }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let (a, b) = (vec![1, 2], vec![3]);
+    /// let mut chain = (&a).lend_refs().chain((&b).lend_refs());
+    /// while let Some(x) = chain.next() {
+    ///     println!("{x}");
+    /// }
+    /// ```
+    ///
+    /// When the lending iterators are built from regular iterators, chaining those first
+    /// lends the same items from borrowed data:
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let (a, b) = (vec![1, 2], vec![3]);
+    /// let mut chain = a.iter().chain(b.iter()).lend_refs();
+    /// assert_eq!(chain.next(), Some(&&1));
+    /// assert_eq!(chain.next(), Some(&&2));
+    /// assert_eq!(chain.next(), Some(&&3));
+    /// assert_eq!(chain.next(), None);
+    /// ```
+    ///
+    /// Otherwise, moving the data into the lending iterators works:
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let (a, b) = (vec![1, 2, 3], vec![4]);
+    /// let mut chain = a.into_iter().windows(2).chain(b.into_iter().windows(1));
+    /// assert_eq!(chain.next(), Some([1, 2].as_slice()));
+    /// assert_eq!(chain.next(), Some([2, 3].as_slice()));
+    /// assert_eq!(chain.next(), Some([4].as_slice()));
+    /// assert_eq!(chain.next(), None);
+    /// ```
     #[inline]
     fn chain<'a, I>(self, other: I) -> Chain<Self, I>
     where
@@ -142,6 +235,67 @@ pub trait LendingIterator {
         Zip::new(self, other)
     }
 
+    /// Creates a new lending iterator which places a clone of `separator` between
+    /// adjacent items of the original lending iterator.
+    ///
+    /// The separator is converted into an item with [`Into`], so that it can become an
+    /// item of any lifetime. Knowing whether a separator is needed requires the lending
+    /// iterator to implement [`HasNextLendingIterator`]. Lending iterators that can't
+    /// tell, such as windows over a source without an exact size or a filtered lending
+    /// iterator, can buffer one element with [`peekable`](Self::peekable) first.
+    ///
+    /// See [`Iterator::intersperse`].
+    ///
+    /// # Example
+    /// ```
+    /// # use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    /// let mut items = Vec::new();
+    /// (0..7)
+    ///     .into_lending()
+    ///     .filter(|x| x % 3 == 0)
+    ///     .peekable()
+    ///     .intersperse(-1)
+    ///     .for_each(|x| items.push(x));
+    /// assert_eq!(items, [0, -1, 3, -1, 6]);
+    /// ```
+    #[inline]
+    fn intersperse<S>(self, separator: S) -> Intersperse<Self, S>
+    where
+        Self: Sized + HasNextLendingIterator,
+        for<'all> S: Clone + Into<Self::Item<'all>>,
+    {
+        Intersperse::new(self, separator)
+    }
+
+    /// Creates a new lending iterator which places an item generated by `separator`
+    /// between adjacent items of the original lending iterator.
+    ///
+    /// The closure takes a `&()` so that the lifetime of the item it returns can be
+    /// tied to it. Knowing whether a separator is needed requires the lending iterator
+    /// to implement [`HasNextLendingIterator`], which [`peekable`](Self::peekable)
+    /// provides for any lending iterator.
+    ///
+    /// See [`Iterator::intersperse_with`].
+    ///
+    /// # Example
+    /// ```
+    /// # use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    /// let mut records = Vec::new();
+    /// (0..4)
+    ///     .windows(2)
+    ///     .intersperse_with(|_: &()| -> &[i32] { &[-1] })
+    ///     .for_each(|w| records.push(w.to_vec()));
+    /// assert_eq!(records, [vec![0, 1], vec![-1], vec![1, 2], vec![-1], vec![2, 3]]);
+    /// ```
+    #[inline]
+    fn intersperse_with<G>(self, separator: G) -> IntersperseWith<Self, G>
+    where
+        Self: Sized + HasNextLendingIterator,
+        for<'all> G: FnMut(&'all ()) -> Self::Item<'all>,
+    {
+        IntersperseWith::new(self, separator)
+    }
+
     /// Takes a closure and creates a lending iterator which calls that closure on each
     /// element.
     ///
@@ -164,6 +318,40 @@ pub trait LendingIterator {
         Map::new(self, f)
     }
 
+    /// Does something with each element of a lending iterator, passing the value on.
+    ///
+    /// See [`Iterator::inspect`].
+    #[inline]
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        for<'all> F: FnMut(&Self::Item<'all>),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// A lending iterator adapter which, like [`fold`](Self::fold), holds internal state, but
+    /// unlike `fold`, produces a new lending iterator.
+    ///
+    /// The closure receives a tuple of a mutable reference to the state and the
+    /// next element, and returns an [`Option`]. Like [`map`](Self::map),
+    /// the returned value may borrow from its input, in which case you will
+    /// likely need a function rather than a closure on stable rust.
+    ///
+    /// In the case that the closure's return type doesn't borrow from its input,
+    /// the resulting `LendingIterator` will implement [`IntoIterator`].
+    ///
+    /// See [`Iterator::scan`].
+    #[inline]
+    fn scan<St, F>(self, initial_state: St, f: F) -> Scan<Self, St, F>
+    where
+        Self: Sized,
+        for<'all> F: SingleArgFnMut<(&'all mut St, Self::Item<'all>)>,
+        for<'all> <F as SingleArgFnOnce<(&'all mut St, Self::Item<'all>)>>::Output: OptionTrait,
+    {
+        Scan::new(self, initial_state, f)
+    }
+
     /// Borrows an iterator, rather than consuming it.
     ///
     /// See [`Iterator::by_ref`].
@@ -284,6 +472,86 @@ pub trait LendingIterator {
         Cloned::new(self)
     }
 
+    /// Turns this lending iterator into an [`Iterator`], when its items don't borrow
+    /// from it.
+    ///
+    /// This is the case when `Item<'a>` is the same type for every `'a`, e.g. for
+    /// [`into_lending`](crate::ToLendingIterator::into_lending) and adapters of it, or
+    /// a [`filter_map`](Self::filter_map) returning owned values.
+    ///
+    /// Proving this for every `'a` currently requires the lending iterator to be `'static`.
+    #[inline]
+    fn into_owned_iter<T>(self) -> IntoOwnedIter<Self>
+    where
+        Self: Sized,
+        for<'a> Option<Self::Item<'a>>: OptionTrait<Item = T>,
+    {
+        IntoOwnedIter::new(self)
+    }
+
+    /// Transforms a lending iterator into a collection, converting each item with
+    /// [`ToOwnedItem`](crate::ToOwnedItem).
+    ///
+    /// Proving the conversion for every `Item<'a>` currently requires the lending iterator
+    /// to be `'static`.
+    ///
+    /// See [`Iterator::collect`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    #[must_use = "if you really need to exhaust the lending iterator, consider `.for_each(drop)` instead"]
+    fn collect_owned<B>(self) -> B
+    where
+        Self: Sized,
+        B: FromLendingIterator<Self>,
+    {
+        B::from_lending_iter(self)
+    }
+
+    /// Consumes a lending iterator, creating two collections from it.
+    ///
+    /// The elements for which `f` returns `true` are converted with
+    /// [`ToOwnedItem`] and put in the first collection, the rest in the second one.
+    ///
+    /// See [`Iterator::partition`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn partition_owned<B, F>(self, mut f: F) -> (B, B)
+    where
+        Self: Sized,
+        for<'all> Self::Item<'all>: ToOwnedItem,
+        for<'all> B: Default + Extend<<Self::Item<'all> as ToOwnedItem>::Owned>,
+        for<'all> F: FnMut(&Self::Item<'all>) -> bool,
+    {
+        let mut left = B::default();
+        let mut right = B::default();
+        self.for_each(|x| {
+            if f(&x) {
+                left.extend(Some(x.to_owned_item()));
+            } else {
+                right.extend(Some(x.to_owned_item()));
+            }
+        });
+        (left, right)
+    }
+
+    /// Converts a lending iterator of pairs into a pair of collections, converting the
+    /// pairs with [`ToOwnedItem`].
+    ///
+    /// See [`Iterator::unzip`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn unzip_owned<A, B, FromA, FromB>(self) -> (FromA, FromB)
+    where
+        Self: Sized,
+        for<'all> Self::Item<'all>: ToOwnedItem<Owned = (A, B)>,
+        FromA: Default + Extend<A>,
+        FromB: Default + Extend<B>,
+    {
+        let mut unzipped: (FromA, FromB) = Default::default();
+        unzipped.extend_lending(self);
+        unzipped
+    }
+
     /// Creates a lending iterator which gives the current iteration count as well as the next value.
     #[inline]
     fn enumerate(self) -> Enumerate<Self>
@@ -293,21 +561,141 @@ pub trait LendingIterator {
         Enumerate::new(self)
     }
 
+    /// Creates a lending iterator which ends after the first [`None`].
+    ///
+    /// See [`Iterator::fuse`].
+    #[inline]
+    fn fuse(self) -> Fuse<Self>
+    where
+        Self: Sized,
+    {
+        Fuse::new(self)
+    }
+
+    /// Boxes this lending iterator behind a [`DynLendingIterator`], so that lending
+    /// iterators of different types with the same items can be stored together or
+    /// returned from trait methods.
+    ///
+    /// The items are named by the [`ForLt`] `L`, e.g. [`Ref<[T]>`](crate::Ref) for
+    /// `windows`. Proving that they match for every lifetime currently requires the
+    /// lending iterator to be `'static`.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn boxed<'a, L>(self) -> BoxedLendingIterator<'a, L>
+    where
+        Self: Sized + DynLendingIterator<L> + 'a,
+        L: ForLt + ?Sized,
+    {
+        BoxedLendingIterator::new(self)
+    }
+
     /// Creates an iterator which can use the [`peek`] and [`peek_mut`] methods
     /// to look at the next element of the iterator without consuming it.
     ///
+    /// The lending iterator is moved to the heap, since the peeked element may borrow
+    /// from it. Use [`peekable_by_ref`](Self::peekable_by_ref) to avoid the allocation.
+    ///
     /// see [`Iterator::peekable`].
     ///
     /// [`peek`]: Peekable::peek
     /// [`peek_mut`]: Peekable::peek_mut
     #[inline]
+    #[cfg(feature = "alloc")]
     fn peekable<'a>(self) -> Peekable<'a, Self>
     where
-        Self: Sized,
+        Self: Sized + 'a,
     {
         Peekable::new(self)
     }
 
+    /// Like [`peekable`](Self::peekable), but borrows the lending iterator instead of
+    /// moving it to the heap, so it doesn't need the `alloc` feature.
+    ///
+    /// As with [`by_ref`](Self::by_ref), the lending iterator can be used again once the
+    /// `Peekable` is dropped. An element that was peeked but not consumed is dropped with
+    /// it.
+    ///
+    /// ```
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().lend_refs();
+    /// let mut peekable = iter.peekable_by_ref();
+    /// assert_eq!(peekable.next(), Some(&1));
+    /// assert_eq!(peekable.peek(|x| **x), Some(2));
+    /// drop(peekable);
+    /// assert_eq!(iter.next(), Some(&3));
+    /// ```
+    #[inline]
+    fn peekable_by_ref(&mut self) -> Peekable<'_, Self>
+    where
+        Self: Sized,
+    {
+        Peekable::new_borrowed(self)
+    }
+
+    /// Creates a lending iterator which can use the [`peek`] and [`peek_mut`] methods to
+    /// look at the next element without consuming it, when its items don't borrow from
+    /// it.
+    ///
+    /// Unlike [`peekable`](Self::peekable), the peeked element is simply buffered next
+    /// to the lending iterator, and [`peek_mut`] returns a mutable reference to it.
+    /// Proving that the items don't borrow currently requires the lending iterator to
+    /// be `'static`.
+    ///
+    /// see [`Iterator::peekable`].
+    ///
+    /// [`peek`]: PeekableOwned::peek
+    /// [`peek_mut`]: PeekableOwned::peek_mut
+    #[inline]
+    fn peekable_owned<T>(self) -> PeekableOwned<Self, T>
+    where
+        Self: Sized,
+        for<'a> Option<Self::Item<'a>>: OptionTrait<Item = T>,
+    {
+        PeekableOwned::new(self)
+    }
+
+    /// Creates a lending iterator which can [`peek`](MultiPeek::peek) arbitrarily far
+    /// ahead, when its items don't borrow from it.
+    ///
+    /// The peeked elements are buffered in a [`VecDeque`](alloc::collections::VecDeque)
+    /// until they are yielded. Proving that the items don't borrow currently requires
+    /// the lending iterator to be `'static`.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn multipeek<T>(self) -> MultiPeek<Self, T>
+    where
+        Self: Sized,
+        for<'a> Option<Self::Item<'a>>: OptionTrait<Item = T>,
+    {
+        MultiPeek::new(self)
+    }
+
+    /// Creates a lending iterator which can [`put_back`](PutBack::put_back) an element
+    /// in front of it, when its items don't borrow from it.
+    ///
+    /// Proving that the items don't borrow currently requires the lending iterator to
+    /// be `'static`.
+    #[inline]
+    fn put_back_owned<T>(self) -> PutBack<Self, T>
+    where
+        Self: Sized,
+        for<'a> Option<Self::Item<'a>>: OptionTrait<Item = T>,
+    {
+        PutBack::new(self)
+    }
+
+    /// Reverses a lending iterator's direction.
+    ///
+    /// See [`Iterator::rev`].
+    #[inline]
+    fn rev(self) -> Rev<Self>
+    where
+        Self: Sized + DoubleEndedLendingIterator,
+    {
+        Rev::new(self)
+    }
+
     /// Creates a lending iterator that skips over the first `n` elements of self.
     #[inline]
     fn skip(self, n: usize) -> Skip<Self>
@@ -321,16 +709,54 @@ pub trait LendingIterator {
     ///
     /// see [`Iterator::skip_while`].
     ///
-    /// [`skip`]: Iterator::skip
-    // #[inline]
-    // #[doc(alias = "drop_while")]
-    // fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
-    // where
-    //     Self: Sized,
-    //     for<'all> P: FnMut(&Self::Item<'all>) -> bool,
-    // {
-    //     SkipWhile::new(self, predicate)
-    // }
+    /// [`skip`]: Self::skip
+    #[inline]
+    #[doc(alias = "drop_while")]
+    fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+    where
+        Self: Sized,
+        for<'all> P: FnMut(&Self::Item<'all>) -> bool,
+    {
+        SkipWhile::new(self, predicate)
+    }
+
+    /// Tests if every element of the lending iterator matches a predicate.
+    ///
+    /// See [`Iterator::all`].
+    #[inline]
+    fn all<P>(&mut self, mut predicate: P) -> bool
+    where
+        Self: Sized,
+        for<'all> P: FnMut(Self::Item<'all>) -> bool,
+    {
+        self.try_fold((), |(), x| {
+            if predicate(x) {
+                ControlFlow::Continue(())
+            } else {
+                ControlFlow::Break(())
+            }
+        })
+        .is_continue()
+    }
+
+    /// Tests if any element of the lending iterator matches a predicate.
+    ///
+    /// See [`Iterator::any`].
+    #[inline]
+    fn any<P>(&mut self, mut predicate: P) -> bool
+    where
+        Self: Sized,
+        for<'all> P: FnMut(Self::Item<'all>) -> bool,
+    {
+        self.try_fold((), |(), x| {
+            if predicate(x) {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_break()
+    }
 
     /// Searches for an element of an iterator that satisfies a predicate.
     ///
@@ -368,6 +794,43 @@ pub trait LendingIterator {
         None
     }
 
+    /// Searches for an element in a lending iterator, returning its index.
+    ///
+    /// See [`Iterator::position`].
+    #[inline]
+    fn position<P>(&mut self, mut predicate: P) -> Option<usize>
+    where
+        Self: Sized,
+        for<'all> P: FnMut(Self::Item<'all>) -> bool,
+    {
+        match self.try_fold(0, |i, x| {
+            if predicate(x) {
+                ControlFlow::Break(i)
+            } else {
+                ControlFlow::Continue(i + 1)
+            }
+        }) {
+            ControlFlow::Break(i) => Some(i),
+            ControlFlow::Continue(_) => None,
+        }
+    }
+
+    /// Checks if the elements of this lending iterator are partitioned according to the given
+    /// predicate, such that all those that return `true` precede all those that return `false`.
+    ///
+    /// See [`Iterator::is_partitioned`].
+    #[inline]
+    #[allow(clippy::wrong_self_convention)]
+    fn is_partitioned<P>(mut self, mut predicate: P) -> bool
+    where
+        Self: Sized,
+        for<'all> P: FnMut(Self::Item<'all>) -> bool,
+    {
+        // Either all items test `true`, or the first clause stops at `false`
+        // and we check that there are no more `true` items after that.
+        self.all(&mut predicate) || !self.any(predicate)
+    }
+
     /// Applies function to the elements of iterator and returns
     /// the first true result or the first error.
     ///
@@ -390,6 +853,379 @@ pub trait LendingIterator {
         }
         Try::from_output(None)
     }
+
+    /// Sums the elements of a lending iterator.
+    ///
+    /// See [`Iterator::sum`].
+    #[inline]
+    fn sum<S>(self) -> S
+    where
+        Self: Sized,
+        S: SumLending<Self>,
+    {
+        S::sum_lending(self)
+    }
+
+    /// Iterates over the entire lending iterator, multiplying all the elements.
+    ///
+    /// See [`Iterator::product`].
+    #[inline]
+    fn product<P>(self) -> P
+    where
+        Self: Sized,
+        P: ProductLending<Self>,
+    {
+        P::product_lending(self)
+    }
+
+    /// Returns the index and key of the element that gives the maximum value from the
+    /// specified function.
+    ///
+    /// The key can't borrow from the element, as it has to outlive it. If several
+    /// elements are equally maximum, the last one is returned. If the lending iterator
+    /// is empty, [`None`] is returned.
+    ///
+    /// See [`Iterator::max_by_key`].
+    #[inline]
+    fn max_by_key<B, F>(self, mut f: F) -> Option<(usize, B)>
+    where
+        Self: Sized,
+        B: Ord,
+        for<'all> F: FnMut(&Self::Item<'all>) -> B,
+    {
+        self.fold((0, None), |(i, max), x| {
+            let key = f(&x);
+            let max = match max {
+                Some((j, max)) if key < max => Some((j, max)),
+                _ => Some((i, key)),
+            };
+            (i + 1, max)
+        })
+        .1
+    }
+
+    /// Returns the index and key of the element that gives the minimum value from the
+    /// specified function.
+    ///
+    /// The key can't borrow from the element, as it has to outlive it. If several
+    /// elements are equally minimum, the first one is returned. If the lending iterator
+    /// is empty, [`None`] is returned.
+    ///
+    /// See [`Iterator::min_by_key`].
+    #[inline]
+    fn min_by_key<B, F>(self, mut f: F) -> Option<(usize, B)>
+    where
+        Self: Sized,
+        B: Ord,
+        for<'all> F: FnMut(&Self::Item<'all>) -> B,
+    {
+        self.fold((0, None), |(i, min), x| {
+            let key = f(&x);
+            let min = match min {
+                Some((j, min)) if min <= key => Some((j, min)),
+                _ => Some((i, key)),
+            };
+            (i + 1, min)
+        })
+        .1
+    }
+
+    /// Returns an owned copy of the maximum element of the lending iterator.
+    ///
+    /// Elements are compared through [`Deref`], and the current maximum is only
+    /// converted with [`ToOwned`] when it changes, reusing its allocation. If several
+    /// elements are equally maximum, the last one is returned. If the lending iterator
+    /// is empty, [`None`] is returned.
+    ///
+    /// See [`Iterator::max`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn max_owned<T>(self) -> Option<T::Owned>
+    where
+        Self: Sized,
+        for<'all> Self::Item<'all>: Deref<Target = T>,
+        T: Ord + ToOwned + ?Sized,
+    {
+        self.fold(None, |max: Option<T::Owned>, x| match max {
+            Some(max) if *x < *max.borrow() => Some(max),
+            Some(mut max) => {
+                x.clone_into(&mut max);
+                Some(max)
+            }
+            None => Some(x.to_owned()),
+        })
+    }
+
+    /// Returns an owned copy of the minimum element of the lending iterator.
+    ///
+    /// Elements are compared through [`Deref`], and the current minimum is only
+    /// converted with [`ToOwned`] when it changes, reusing its allocation. If several
+    /// elements are equally minimum, the first one is returned. If the lending iterator
+    /// is empty, [`None`] is returned.
+    ///
+    /// See [`Iterator::min`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn min_owned<T>(self) -> Option<T::Owned>
+    where
+        Self: Sized,
+        for<'all> Self::Item<'all>: Deref<Target = T>,
+        T: Ord + ToOwned + ?Sized,
+    {
+        self.fold(None, |min: Option<T::Owned>, x| match min {
+            Some(min) if *min.borrow() <= *x => Some(min),
+            Some(mut min) => {
+                x.clone_into(&mut min);
+                Some(min)
+            }
+            None => Some(x.to_owned()),
+        })
+    }
+
+    /// Reduces the elements to a single owned value, by converting the first element
+    /// with [`ToOwned`] and then repeatedly applying a reducing operation.
+    ///
+    /// If the lending iterator is empty, [`None`] is returned.
+    ///
+    /// See [`Iterator::reduce`].
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn reduce_owned<T, F>(mut self, f: F) -> Option<T::Owned>
+    where
+        Self: Sized,
+        for<'all> Self::Item<'all>: Deref<Target = T>,
+        T: ToOwned + ?Sized,
+        for<'all> F: FnMut(T::Owned, Self::Item<'all>) -> T::Owned,
+    {
+        let first = self.next()?.to_owned();
+        Some(self.fold(first, f))
+    }
+
+    /// Lexicographically compares the elements of this lending iterator with those
+    /// of another.
+    ///
+    /// The items of both lending iterators are usually different types, or the same type
+    /// lent for different lifetimes, so they are compared through the [`Ord`] type `T`
+    /// they both [`Borrow`], such as `[i32]` for `&[i32]` windows and `Vec<i32>`s.
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use gat_lending_iterator::{LendingIterator, ToLendingIterator};
+    ///
+    /// let golden = vec![vec![1, 2], vec![2, 4]];
+    /// assert_eq!((1..4).windows(2).cmp(golden.into_lending()), Ordering::Less);
+    /// ```
+    ///
+    /// `T` has to be named when both items also borrow as themselves, as with `String`s
+    /// and `&str`s: `cmp::<_, str>(other)`.
+    ///
+    /// To compare against an [`Iterator`], turn it into a lending iterator with
+    /// [`into_lending`](crate::ToLendingIterator::into_lending) first.
+    ///
+    /// See [`Iterator::cmp`].
+    #[inline]
+    fn cmp<I, T>(self, other: I) -> Ordering
+    where
+        Self: Sized,
+        I: LendingIterator,
+        T: Ord + ?Sized,
+        for<'all> Self::Item<'all>: Borrow<T>,
+        for<'other> I::Item<'other>: Borrow<T>,
+    {
+        self.cmp_by(other, |x, y| x.borrow().cmp(y.borrow()))
+    }
+
+    /// Lexicographically compares the elements of this lending iterator with those
+    /// of another with respect to the specified comparison function.
+    ///
+    /// Each pair of items is dropped before either lending iterator is advanced again.
+    ///
+    /// See [`Iterator::cmp_by`].
+    #[inline]
+    fn cmp_by<I, F>(mut self, mut other: I, mut cmp: F) -> Ordering
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> F: FnMut(Self::Item<'all>, I::Item<'other>) -> Ordering,
+    {
+        loop {
+            let Some(x) = self.next() else {
+                return if other.next().is_none() {
+                    Ordering::Equal
+                } else {
+                    Ordering::Less
+                };
+            };
+            let Some(y) = other.next() else {
+                return Ordering::Greater;
+            };
+            match cmp(x, y) {
+                Ordering::Equal => (),
+                non_eq => return non_eq,
+            }
+        }
+    }
+
+    /// Lexicographically compares the [`PartialOrd`] elements of this lending iterator
+    /// with those of another.
+    ///
+    /// Each item of `other` is compared against the corresponding item of `self`.
+    ///
+    /// See [`Iterator::partial_cmp`].
+    #[inline]
+    fn partial_cmp<I>(self, other: I) -> Option<Ordering>
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> I::Item<'other>: PartialOrd<Self::Item<'all>>,
+    {
+        self.partial_cmp_by(other, |x, y| y.partial_cmp(&x).map(Ordering::reverse))
+    }
+
+    /// Lexicographically compares the elements of this lending iterator with those
+    /// of another with respect to the specified comparison function.
+    ///
+    /// See [`Iterator::partial_cmp_by`].
+    #[inline]
+    fn partial_cmp_by<I, F>(mut self, mut other: I, mut partial_cmp: F) -> Option<Ordering>
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> F: FnMut(Self::Item<'all>, I::Item<'other>) -> Option<Ordering>,
+    {
+        loop {
+            let Some(x) = self.next() else {
+                return if other.next().is_none() {
+                    Some(Ordering::Equal)
+                } else {
+                    Some(Ordering::Less)
+                };
+            };
+            let Some(y) = other.next() else {
+                return Some(Ordering::Greater);
+            };
+            match partial_cmp(x, y) {
+                Some(Ordering::Equal) => (),
+                non_eq => return non_eq,
+            }
+        }
+    }
+
+    /// Determines if the elements of this lending iterator are equal to those of another.
+    ///
+    /// Each item of `other` is compared against the corresponding item of `self`.
+    ///
+    /// See [`Iterator::eq`].
+    #[inline]
+    fn eq<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> I::Item<'other>: PartialEq<Self::Item<'all>>,
+    {
+        self.eq_by(other, |x, y| y == x)
+    }
+
+    /// Determines if the elements of this lending iterator are equal to those of another
+    /// with respect to the specified equality function.
+    ///
+    /// Each pair of items is dropped before either lending iterator is advanced again.
+    ///
+    /// See [`Iterator::eq_by`].
+    #[inline]
+    fn eq_by<I, F>(mut self, mut other: I, mut eq: F) -> bool
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> F: FnMut(Self::Item<'all>, I::Item<'other>) -> bool,
+    {
+        loop {
+            let Some(x) = self.next() else {
+                return other.next().is_none();
+            };
+            let Some(y) = other.next() else {
+                return false;
+            };
+            if !eq(x, y) {
+                return false;
+            }
+        }
+    }
+
+    /// Determines if the elements of this lending iterator are not equal to those of another.
+    ///
+    /// See [`Iterator::ne`].
+    #[inline]
+    fn ne<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> I::Item<'other>: PartialEq<Self::Item<'all>>,
+    {
+        !self.eq(other)
+    }
+
+    /// Determines if the elements of this lending iterator are [lexicographically](Ord#lexicographical-comparison)
+    /// less than those of another.
+    ///
+    /// See [`Iterator::lt`].
+    #[inline]
+    fn lt<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> I::Item<'other>: PartialOrd<Self::Item<'all>>,
+    {
+        self.partial_cmp(other) == Some(Ordering::Less)
+    }
+
+    /// Determines if the elements of this lending iterator are [lexicographically](Ord#lexicographical-comparison)
+    /// less or equal to those of another.
+    ///
+    /// See [`Iterator::le`].
+    #[inline]
+    fn le<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> I::Item<'other>: PartialOrd<Self::Item<'all>>,
+    {
+        matches!(
+            self.partial_cmp(other),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// Determines if the elements of this lending iterator are [lexicographically](Ord#lexicographical-comparison)
+    /// greater than those of another.
+    ///
+    /// See [`Iterator::gt`].
+    #[inline]
+    fn gt<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> I::Item<'other>: PartialOrd<Self::Item<'all>>,
+    {
+        self.partial_cmp(other) == Some(Ordering::Greater)
+    }
+
+    /// Determines if the elements of this lending iterator are [lexicographically](Ord#lexicographical-comparison)
+    /// greater than or equal to those of another.
+    ///
+    /// See [`Iterator::ge`].
+    #[inline]
+    fn ge<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        I: LendingIterator,
+        for<'all, 'other> I::Item<'other>: PartialOrd<Self::Item<'all>>,
+    {
+        matches!(
+            self.partial_cmp(other),
+            Some(Ordering::Greater | Ordering::Equal)
+        )
+    }
 }
 
 impl<I: LendingIterator + ?Sized> LendingIterator for &mut I {
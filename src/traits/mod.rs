@@ -1,9 +1,21 @@
+mod accum;
+mod double_ended;
+mod dyn_lending_iterator;
 mod exact_size;
+#[cfg(feature = "alloc")]
+mod from_lending_iterator;
 mod functions;
+mod fused;
 mod lending_iterator;
 mod to_lending_iterator;
+pub use self::accum::{ProductLending, SumLending};
+pub use self::double_ended::DoubleEndedLendingIterator;
+pub use self::dyn_lending_iterator::{DynLendingIterator, ForLt, ItemAt, Owned, Ref, RefMut};
 pub use self::exact_size::{ExactSizeLendingIterator, HasNextLendingIterator};
+#[cfg(feature = "alloc")]
+pub use self::from_lending_iterator::{ExtendLending, FromLendingIterator, ToOwnedItem};
 pub use self::functions::*;
+pub use self::fused::FusedLendingIterator;
 pub use self::lending_iterator::*;
 pub use self::to_lending_iterator::ToLendingIterator;
-// MAYBE: IntoLendingIterator (chain, zip), FusedLendingIterator, DoubleEndedLendingIterator, Sum, Product, etc.
+// MAYBE: IntoLendingIterator (chain, zip), etc.
@@ -1,6 +1,6 @@
-use crate::{IntoLending, LendRefs, LendRefsMut};
+use crate::{ArrayWindows, ArrayWindowsMut, IntoLending, LendRefs, LendRefsMut};
 #[cfg(feature = "alloc")]
-use crate::{Windows, WindowsMut};
+use crate::{Chunks, ChunksExact, ChunksMut, Windows, WindowsMut, WindowsStep, WindowsStepMut};
 /// An extension trait for iterators that allows turning them into lending iterators (over windows of elements).
 pub trait ToLendingIterator: IntoIterator {
     /// Turns this iterator into a lending iterator over windows of elements (&\[Item\]).
@@ -8,13 +8,14 @@ pub trait ToLendingIterator: IntoIterator {
     /// `Windows` is backed by a buffer that grows to at most size * 2.
     /// This was chosen as a compromise between memory usage and time complexity:
     /// if the buffer was limited to size `size`, we would need to shift all the elements
-    /// on every iteration.
+    /// on every iteration. Use [`windows_with_capacity`](ToLendingIterator::windows_with_capacity)
+    /// to choose a different compromise.
     #[cfg(feature = "alloc")]
     fn windows(self, size: usize) -> Windows<Self::IntoIter>
     where
         Self: Sized,
     {
-        Windows::new(self.into_iter(), size)
+        Windows::new(self.into_iter(), size, size * 2 - 1)
     }
 
     /// Turns this iterator into a lending iterator over mutable windows of elements (&mut \[Item\]).
@@ -22,13 +23,175 @@ pub trait ToLendingIterator: IntoIterator {
     /// `WindowsMut` is backed by a buffer that grows to at most size * 2.
     /// This was chosen as a compromise between memory usage and time complexity:
     /// if the buffer was limited to size `size`, we would need to shift all the elements
-    /// on every iteration.
+    /// on every iteration. Use [`windows_mut_with_capacity`](ToLendingIterator::windows_mut_with_capacity)
+    /// to choose a different compromise.
     #[cfg(feature = "alloc")]
     fn windows_mut(self, size: usize) -> WindowsMut<Self::IntoIter>
     where
         Self: Sized,
     {
-        WindowsMut::new(self.into_iter(), size)
+        WindowsMut::new(self.into_iter(), size, size * 2 - 1)
+    }
+
+    /// Turns this iterator into a lending iterator over windows of elements (&\[Item\]),
+    /// backed by a buffer of `capacity` elements.
+    ///
+    /// A ring buffer would never move the elements, but a window that wraps around its
+    /// end isn't contiguous, and couldn't be lent as a slice without first copying it.
+    /// The windows slide through the buffer instead, and whenever it's full the last
+    /// `size - 1` elements are moved to its front. Each element is therefore moved
+    /// `(size - 1) / (capacity - size + 1)` times on average: every time with a
+    /// `capacity` of `size`, less than once with the `size * 2 - 1` that
+    /// [`windows`](ToLendingIterator::windows) uses, and rarely with larger ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is less than `size`.
+    #[cfg(feature = "alloc")]
+    fn windows_with_capacity(self, size: usize, capacity: usize) -> Windows<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        Windows::new(self.into_iter(), size, capacity)
+    }
+
+    /// Turns this iterator into a lending iterator over mutable windows of elements
+    /// (&mut \[Item\]), backed by a buffer of `capacity` elements.
+    ///
+    /// See [`windows_with_capacity`](ToLendingIterator::windows_with_capacity).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is less than `size`.
+    #[cfg(feature = "alloc")]
+    fn windows_mut_with_capacity(self, size: usize, capacity: usize) -> WindowsMut<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        WindowsMut::new(self.into_iter(), size, capacity)
+    }
+
+    /// Turns this iterator into a lending iterator over windows of `size` elements
+    /// (&\[Item\]) that start `step` elements apart.
+    ///
+    /// This yields the same windows as `windows(size).step_by(step)`, but only buffers
+    /// the elements it lends: when `step > size`, the elements between windows are
+    /// skipped with [`Iterator::nth`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `step` is 0.
+    #[cfg(feature = "alloc")]
+    fn windows_step(self, size: usize, step: usize) -> WindowsStep<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        WindowsStep::new(self.into_iter(), size, step)
+    }
+
+    /// Turns this iterator into a lending iterator over mutable windows of `size`
+    /// elements (&mut \[Item\]) that start `step` elements apart.
+    ///
+    /// Changes to the elements that overlap with the next window are seen by it. See
+    /// [`windows_step`](ToLendingIterator::windows_step).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` or `step` is 0.
+    #[cfg(feature = "alloc")]
+    fn windows_step_mut(self, size: usize, step: usize) -> WindowsStepMut<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        WindowsStepMut::new(self.into_iter(), size, step)
+    }
+
+    /// Turns this iterator into a lending iterator over chunks of `size` elements
+    /// (&\[Item\]), where the last chunk may be shorter.
+    ///
+    /// Every chunk is read into the same buffer, so only one chunk is allocated.
+    ///
+    /// See [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn chunks(self, size: usize) -> Chunks<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self.into_iter(), size)
+    }
+
+    /// Turns this iterator into a lending iterator over mutable chunks of `size`
+    /// elements (&mut \[Item\]), where the last chunk may be shorter.
+    ///
+    /// See [`chunks`](ToLendingIterator::chunks).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn chunks_mut(self, size: usize) -> ChunksMut<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        ChunksMut::new(self.into_iter(), size)
+    }
+
+    /// Turns this iterator into a lending iterator over chunks of exactly `size`
+    /// elements (&\[Item\]).
+    ///
+    /// The elements left over at the end can be retrieved with
+    /// [`remainder`](ChunksExact::remainder).
+    ///
+    /// See [`slice::chunks_exact`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    #[cfg(feature = "alloc")]
+    fn chunks_exact(self, size: usize) -> ChunksExact<Self::IntoIter>
+    where
+        Self: Sized,
+    {
+        ChunksExact::new(self.into_iter(), size)
+    }
+
+    /// Turns this iterator into a lending iterator over windows of `N` elements
+    /// (&\[Item; N\]).
+    ///
+    /// Unlike `windows`, `ArrayWindows` doesn't allocate: it's backed by an inline buffer
+    /// of `2 * N` elements, and each element is moved at most once after it's read.
+    ///
+    /// A window size of 0 is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use gat_lending_iterator::ToLendingIterator;
+    ///
+    /// let _ = (0..3).array_windows::<0>();
+    /// ```
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self::IntoIter, N>
+    where
+        Self: Sized,
+    {
+        ArrayWindows::new(self.into_iter())
+    }
+
+    /// Turns this iterator into a lending iterator over mutable windows of `N` elements
+    /// (&mut \[Item; N\]).
+    ///
+    /// Unlike `windows_mut`, `ArrayWindowsMut` doesn't allocate: it's backed by an inline
+    /// buffer of `2 * N` elements, and each element is moved at most once after it's read.
+    ///
+    /// A window size of 0 is rejected at compile time, as with
+    /// [`array_windows`](ToLendingIterator::array_windows).
+    fn array_windows_mut<const N: usize>(self) -> ArrayWindowsMut<Self::IntoIter, N>
+    where
+        Self: Sized,
+    {
+        ArrayWindowsMut::new(self.into_iter())
     }
 
     /// Turns this iterator into a lending iterator trivially.